@@ -351,6 +351,9 @@ _rtx() {
             rtx__help__direnv,exec)
                 cmd="rtx__help__direnv__exec"
                 ;;
+            rtx__help__plugins,help)
+                cmd="rtx__help__plugins__help"
+                ;;
             rtx__help__plugins,install)
                 cmd="rtx__help__plugins__install"
                 ;;
@@ -363,12 +366,24 @@ _rtx() {
             rtx__help__plugins,ls-remote)
                 cmd="rtx__help__plugins__ls__remote"
                 ;;
+            rtx__help__plugins,new)
+                cmd="rtx__help__plugins__new"
+                ;;
+            rtx__help__plugins,registry)
+                cmd="rtx__help__plugins__registry"
+                ;;
+            rtx__help__plugins,test)
+                cmd="rtx__help__plugins__test"
+                ;;
             rtx__help__plugins,uninstall)
                 cmd="rtx__help__plugins__uninstall"
                 ;;
             rtx__help__plugins,update)
                 cmd="rtx__help__plugins__update"
                 ;;
+            rtx__help__plugins__registry,update)
+                cmd="rtx__help__plugins__registry__update"
+                ;;
             rtx__help__settings,get)
                 cmd="rtx__help__settings__get"
                 ;;
@@ -408,32 +423,23 @@ _rtx() {
             rtx__plugins,ls-remote)
                 cmd="rtx__plugins__ls__remote"
                 ;;
+            rtx__plugins,new)
+                cmd="rtx__plugins__new"
+                ;;
+            rtx__plugins,registry)
+                cmd="rtx__plugins__registry"
+                ;;
+            rtx__plugins,test)
+                cmd="rtx__plugins__test"
+                ;;
             rtx__plugins,uninstall)
                 cmd="rtx__plugins__uninstall"
                 ;;
             rtx__plugins,update)
                 cmd="rtx__plugins__update"
                 ;;
-            rtx__plugins__help,help)
-                cmd="rtx__plugins__help__help"
-                ;;
-            rtx__plugins__help,install)
-                cmd="rtx__plugins__help__install"
-                ;;
-            rtx__plugins__help,link)
-                cmd="rtx__plugins__help__link"
-                ;;
-            rtx__plugins__help,ls)
-                cmd="rtx__plugins__help__ls"
-                ;;
-            rtx__plugins__help,ls-remote)
-                cmd="rtx__plugins__help__ls__remote"
-                ;;
-            rtx__plugins__help,uninstall)
-                cmd="rtx__plugins__help__uninstall"
-                ;;
-            rtx__plugins__help,update)
-                cmd="rtx__plugins__help__update"
+            rtx__plugins__registry,update)
+                cmd="rtx__plugins__registry__update"
                 ;;
             rtx__settings,add)
                 cmd="rtx__settings__set"
@@ -1737,7 +1743,7 @@ _rtx() {
             return 0
             ;;
         rtx__help__plugins)
-            opts="install link ls ls-remote uninstall update"
+            opts="help install link ls ls-remote new registry test uninstall update"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1750,6 +1756,20 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        rtx__help__plugins__help)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         rtx__help__plugins__install)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -1806,6 +1826,62 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        rtx__help__plugins__new)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        rtx__help__plugins__registry)
+            opts="update"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        rtx__help__plugins__registry__update)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        rtx__help__plugins__test)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         rtx__help__plugins__uninstall)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -2119,7 +2195,7 @@ _rtx() {
             return 0
             ;;
         rtx__install)
-            opts="-f -v -j -r -h --force --verbose --debug --install-missing --jobs --log-level --raw --trace --help [TOOL]..."
+            opts="-f -v -j -r -h --force --locked --verbose --debug --install-missing --jobs --log-level --raw --trace --help [TOOL]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2261,7 +2337,7 @@ _rtx() {
             return 0
             ;;
         rtx__plugins)
-            opts="-a -c -u -j -r -v -h --all --core --urls --debug --install-missing --jobs --log-level --raw --trace --verbose --help install link ls ls-remote uninstall update help"
+            opts="-a -c -u -j -r -v -h --all --core --urls --debug --install-missing --jobs --log-level --raw --trace --verbose --help help install link ls ls-remote new registry test uninstall update"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2287,54 +2363,24 @@ _rtx() {
             return 0
             ;;
         rtx__plugins__help)
-            opts="install link ls ls-remote uninstall update help"
+            opts="-j -r -v -h --debug --install-missing --jobs --log-level --raw --trace --verbose --help <TOOL> [ASDF_VERSION]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
-                *)
-                    COMPREPLY=()
+                --jobs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
                     ;;
-            esac
-            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-            return 0
-            ;;
-        rtx__plugins__help__help)
-            opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
-                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-                return 0
-            fi
-            case "${prev}" in
-                *)
-                    COMPREPLY=()
+                -j)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
                     ;;
-            esac
-            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-            return 0
-            ;;
-        rtx__plugins__help__install)
-            opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
-                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-                return 0
-            fi
-            case "${prev}" in
-                *)
-                    COMPREPLY=()
+                --log-level)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
                     ;;
-            esac
-            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-            return 0
-            ;;
-        rtx__plugins__help__link)
-            opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
-                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
-                return 0
-            fi
-            case "${prev}" in
                 *)
                     COMPREPLY=()
                     ;;
@@ -2342,13 +2388,25 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        rtx__plugins__help__ls)
-            opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+        rtx__plugins__install)
+            opts="-f -a -v -j -r -h --force --all --verbose --debug --install-missing --jobs --log-level --raw --trace --help [NAME] [GIT_URL] [REST]..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --jobs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -j)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --log-level)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -2356,13 +2414,25 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        rtx__plugins__help__ls__remote)
-            opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+        rtx__plugins__link)
+            opts="-f -j -r -v -h --force --debug --install-missing --jobs --log-level --raw --trace --verbose --help <NAME> [PATH]"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --jobs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -j)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --log-level)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -2370,13 +2440,25 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        rtx__plugins__help__uninstall)
-            opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+        rtx__plugins__ls)
+            opts="-a -c -u -j -r -v -h --all --core --urls --debug --install-missing --jobs --log-level --raw --trace --verbose --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --jobs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -j)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --log-level)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -2384,13 +2466,33 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        rtx__plugins__help__update)
-            opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+        rtx__plugins__ls__remote)
+            opts="-u -s -j -r -v -h --urls --only-names --search --debug --install-missing --jobs --log-level --raw --trace --verbose --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --search)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -s)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --jobs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -j)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --log-level)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -2398,13 +2500,29 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        rtx__plugins__install)
-            opts="-f -a -v -j -r -h --force --all --verbose --debug --install-missing --jobs --log-level --raw --trace --help [NAME] [GIT_URL] [REST]..."
+        rtx__plugins__new)
+            opts="-j -r -v -h --repo --tag-prefix --template --url --debug --install-missing --jobs --log-level --raw --trace --verbose --help <NAME> [PATH]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --repo)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --tag-prefix)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --template)
+                    COMPREPLY=($(compgen -W "github tarball source" -- "${cur}"))
+                    return 0
+                    ;;
+                --url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --jobs)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -2424,8 +2542,8 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        rtx__plugins__link)
-            opts="-f -j -r -v -h --force --debug --install-missing --jobs --log-level --raw --trace --verbose --help <NAME> [PATH]"
+        rtx__plugins__registry)
+            opts="-j -r -v -h --debug --install-missing --jobs --log-level --raw --trace --verbose --help update"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2450,9 +2568,9 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        rtx__plugins__ls)
-            opts="-a -c -u -j -r -v -h --all --core --urls --debug --install-missing --jobs --log-level --raw --trace --verbose --help"
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+        rtx__plugins__registry__update)
+            opts="-j -r -v -h --debug --install-missing --jobs --log-level --raw --trace --verbose --help [SOURCE]"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
@@ -2476,13 +2594,17 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        rtx__plugins__ls__remote)
-            opts="-u -j -r -v -h --urls --only-names --debug --install-missing --jobs --log-level --raw --trace --verbose --help"
+        rtx__plugins__test)
+            opts="-j -r -v -h --tool-version --isolated --debug --install-missing --jobs --log-level --raw --trace --verbose --help <PLUGIN> [COMMAND]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --tool-version)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --jobs)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -2529,7 +2651,7 @@ _rtx() {
             return 0
             ;;
         rtx__plugins__update)
-            opts="-a -j -r -v -h --all --debug --install-missing --jobs --log-level --raw --trace --verbose --help [PLUGIN]..."
+            opts="-a -j -r -v -h --all --dry-run --debug --install-missing --jobs --log-level --raw --trace --verbose --help [PLUGIN]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -328,6 +328,7 @@ complete -c rtx -n "__fish_seen_subcommand_from install" -s j -l jobs -d 'Number
 default: 4' -r
 complete -c rtx -n "__fish_seen_subcommand_from install" -l log-level -d 'Set the log output verbosity' -r
 complete -c rtx -n "__fish_seen_subcommand_from install" -s f -l force -d 'Force reinstall even if already installed'
+complete -c rtx -n "__fish_seen_subcommand_from install" -l locked -d 'Install the versions and plugin commits pinned in .rtx.lock files'
 complete -c rtx -n "__fish_seen_subcommand_from install" -s v -l verbose -d 'Show installation output'
 complete -c rtx -n "__fish_seen_subcommand_from install" -l debug -d 'Sets log level to debug'
 complete -c rtx -n "__fish_seen_subcommand_from install" -l install-missing -d 'Automatically install missing tools'
@@ -387,27 +388,40 @@ Sets --jobs=1'
 complete -c rtx -n "__fish_seen_subcommand_from ls-remote" -l trace -d 'Sets log level to trace'
 complete -c rtx -n "__fish_seen_subcommand_from ls-remote" -s v -l verbose -d 'Show installation output'
 complete -c rtx -n "__fish_seen_subcommand_from ls-remote" -s h -l help -d 'Print help (see more with \'--help\')'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
 default: 4' -r
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -l log-level -d 'Set the log output verbosity' -r
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -s a -l all -d 'list all available remote plugins'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -s c -l core -d 'The built-in plugins only
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -l log-level -d 'Set the log output verbosity' -r
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -s a -l all -d 'list all available remote plugins'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -s c -l core -d 'The built-in plugins only
 Normally these are not shown'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -s u -l urls -d 'show the git url for each plugin'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -l debug -d 'Sets log level to debug'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -l install-missing -d 'Automatically install missing tools'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.
-Sets --jobs=1'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -l trace -d 'Sets log level to trace'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -s v -l verbose -d 'Show installation output'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -s h -l help -d 'Print help (see more with \'--help\')'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -f -a "install" -d 'Install a plugin'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -f -a "link" -d 'Symlinks a plugin into rtx'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -f -a "ls" -d 'List installed plugins'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -f -a "ls-remote" -d 'List all available remote plugins'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -f -a "uninstall" -d 'Removes a plugin'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -f -a "update" -d 'Updates a plugin to the latest version'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -s u -l urls -d 'show the git url for each plugin'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -l debug -d 'Sets log level to debug'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -l install-missing -d 'Automatically install missing tools'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -l trace -d 'Sets log level to trace'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -s v -l verbose -d 'Show installation output'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "help" -d 'Shows the documentation of a plugin'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "install" -d 'Install a plugin'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "link" -d 'Symlinks a plugin into rtx'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "ls" -d 'List installed plugins'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "ls-remote" -d 'List all available remote plugins'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "new" -d 'Creates a new plugin from a template'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "registry" -d 'Manage the registry of plugin shorthands'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "test" -d 'Tests a plugin by installing it and a version of its tool'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "uninstall" -d 'Removes a plugin'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "update" -d 'Updates a plugin to the latest version'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from help" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
+default: 4' -r
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from help" -l log-level -d 'Set the log output verbosity' -r
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from help" -l debug -d 'Sets log level to debug'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from help" -l install-missing -d 'Automatically install missing tools'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from help" -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from help" -l trace -d 'Sets log level to trace'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from help" -s v -l verbose -d 'Show installation output'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from help" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from install" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
 default: 4' -r
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from install" -l log-level -d 'Set the log output verbosity' -r
@@ -449,6 +463,7 @@ Sets --jobs=1'
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from ls" -l trace -d 'Sets log level to trace'
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from ls" -s v -l verbose -d 'Show installation output'
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from ls" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from ls-remote" -s s -l search -d 'Only show plugins with this term in their name or description' -r
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from ls-remote" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
 default: 4' -r
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from ls-remote" -l log-level -d 'Set the log output verbosity' -r
@@ -461,6 +476,56 @@ Sets --jobs=1'
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from ls-remote" -l trace -d 'Sets log level to trace'
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from ls-remote" -s v -l verbose -d 'Show installation output'
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from ls-remote" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from new" -l repo -d 'The git repository of the tool, its tags are the versions
+e.g.: https://github.com/BurntSushi/ripgrep or BurntSushi/ripgrep for GitHub' -r
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from new" -l tag-prefix -d 'The prefix of the tags that are versions, it is removed from the version
+e.g.: "jq-" for jq-1.7.1, "" for tags without a prefix' -r
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from new" -l template -d 'How the plugin installs the tool' -r -f -a "{github	downloads a GitHub release asset,tarball	downloads a .tar.gz from any url,source	clones the repository and builds it}"
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from new" -l url -d 'The url of the .tar.gz to install, can use {{version}}, {{os}} and {{arch}}
+defaults to the GitHub release <NAME>-<TAG>-{{os}}-{{arch}}.tar.gz' -r
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from new" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
+default: 4' -r
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from new" -l log-level -d 'Set the log output verbosity' -r
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from new" -l debug -d 'Sets log level to debug'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from new" -l install-missing -d 'Automatically install missing tools'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from new" -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from new" -l trace -d 'Sets log level to trace'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from new" -s v -l verbose -d 'Show installation output'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from new" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from update" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
+default: 4' -r
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from update" -l log-level -d 'Set the log output verbosity' -r
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from update" -l debug -d 'Sets log level to debug'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from update" -l install-missing -d 'Automatically install missing tools'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from update" -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from update" -l trace -d 'Sets log level to trace'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from update" -s v -l verbose -d 'Show installation output'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from update" -s h -l help -d 'Print help'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from update" -f -a "update" -d 'Downloads the latest registry of plugin shorthands'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and __fish_seen_subcommand_from update" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
+default: 4' -r
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and __fish_seen_subcommand_from update" -l log-level -d 'Set the log output verbosity' -r
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and __fish_seen_subcommand_from update" -l debug -d 'Sets log level to debug'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and __fish_seen_subcommand_from update" -l install-missing -d 'Automatically install missing tools'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and __fish_seen_subcommand_from update" -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and __fish_seen_subcommand_from update" -l trace -d 'Sets log level to trace'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and __fish_seen_subcommand_from update" -s v -l verbose -d 'Show installation output'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and __fish_seen_subcommand_from update" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from test" -l tool-version -d 'The version of the tool to install' -r
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from test" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
+default: 4' -r
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from test" -l log-level -d 'Set the log output verbosity' -r
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from test" -l isolated -d 'set when rtx runs itself inside of the temporary data directory'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from test" -l debug -d 'Sets log level to debug'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from test" -l install-missing -d 'Automatically install missing tools'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from test" -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.
+Sets --jobs=1'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from test" -l trace -d 'Sets log level to trace'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from test" -s v -l verbose -d 'Show installation output'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from test" -s h -l help -d 'Print help (see more with \'--help\')'
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from uninstall" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
 default: 4' -r
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from uninstall" -l log-level -d 'Set the log output verbosity' -r
@@ -475,6 +540,7 @@ complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcomm
 default: 4' -r
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from update" -l log-level -d 'Set the log output verbosity' -r
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from update" -s a -l all -d 'Update all plugins'
+complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from update" -l dry-run -d 'Only fetch the plugins and show what would be updated'
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from update" -l debug -d 'Sets log level to debug'
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from update" -l install-missing -d 'Automatically install missing tools'
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from update" -s r -l raw -d 'Directly pipe stdin/stdout/stderr to user.
@@ -482,13 +548,6 @@ Sets --jobs=1'
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from update" -l trace -d 'Sets log level to trace'
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from update" -s v -l verbose -d 'Show installation output'
 complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from update" -s h -l help -d 'Print help (see more with \'--help\')'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -f -a "install" -d 'Install a plugin'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -f -a "link" -d 'Symlinks a plugin into rtx'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -f -a "ls" -d 'List installed plugins'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -f -a "ls-remote" -d 'List all available remote plugins'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -f -a "uninstall" -d 'Removes a plugin'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -f -a "update" -d 'Updates a plugin to the latest version'
-complete -c rtx -n "__fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
 complete -c rtx -n "__fish_seen_subcommand_from prune" -s j -l jobs -d 'Number of plugins and runtimes to install in parallel
 default: 4' -r
 complete -c rtx -n "__fish_seen_subcommand_from prune" -l log-level -d 'Set the log output verbosity' -r
@@ -718,12 +777,17 @@ for direnv to consume.'
 complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from envrc; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from activate" -f -a "exec" -d '[internal] This is an internal command that writes an envrc file
 for direnv to consume.'
 complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from direnv; and not __fish_seen_subcommand_from envrc; and not __fish_seen_subcommand_from exec; and not __fish_seen_subcommand_from activate" -f -a "activate" -d 'Output direnv function to use rtx inside direnv'
-complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "install" -d 'Install a plugin'
-complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "link" -d 'Symlinks a plugin into rtx'
-complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "ls" -d 'List installed plugins'
-complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "ls-remote" -d 'List all available remote plugins'
-complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "uninstall" -d 'Removes a plugin'
-complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "update" -d 'Updates a plugin to the latest version'
+complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "help" -d 'Shows the documentation of a plugin'
+complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "install" -d 'Install a plugin'
+complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "link" -d 'Symlinks a plugin into rtx'
+complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "ls" -d 'List installed plugins'
+complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "ls-remote" -d 'List all available remote plugins'
+complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "new" -d 'Creates a new plugin from a template'
+complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "registry" -d 'Manage the registry of plugin shorthands'
+complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "test" -d 'Tests a plugin by installing it and a version of its tool'
+complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "uninstall" -d 'Removes a plugin'
+complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and not __fish_seen_subcommand_from help; and not __fish_seen_subcommand_from install; and not __fish_seen_subcommand_from link; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from ls-remote; and not __fish_seen_subcommand_from new; and not __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from test; and not __fish_seen_subcommand_from uninstall; and not __fish_seen_subcommand_from update" -f -a "update" -d 'Updates a plugin to the latest version'
+complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from plugins; and __fish_seen_subcommand_from registry; and not __fish_seen_subcommand_from update" -f -a "update" -d 'Downloads the latest registry of plugin shorthands'
 complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from get; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from unset" -f -a "get" -d 'Show a current setting'
 complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from get; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from unset" -f -a "ls" -d 'Show current settings'
 complete -c rtx -n "__fish_seen_subcommand_from help; and __fish_seen_subcommand_from settings; and not __fish_seen_subcommand_from get; and not __fish_seen_subcommand_from ls; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from unset" -f -a "set" -d 'Add/update a setting'
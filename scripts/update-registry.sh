@@ -0,0 +1,42 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+rm -rf asdf-plugins
+git clone --depth 1 https://github.com/asdf-vm/asdf-plugins
+rm -f registry.toml
+
+# these replace the asdf plugins with the same name or alias
+custom_plugins=(
+	'go = {repo = "https://github.com/kennyp/asdf-golang.git", description = "Go programming language", aliases = ["golang"]}'
+	'java = {repo = "https://github.com/rtx-plugins/rtx-java.git", description = "Java development kits"}'
+	'node = {repo = "https://github.com/rtx-plugins/rtx-nodejs.git", description = "Node.js JavaScript runtime", aliases = ["nodejs"]}'
+	'pipenv = {repo = "https://github.com/rtx-plugins/rtx-pipenv.git", description = "Python virtualenv and dependency manager"}'
+	'poetry = {repo = "https://github.com/rtx-plugins/rtx-poetry.git", description = "Python packaging and dependency manager"}'
+	'python = {repo = "https://github.com/rtx-plugins/rtx-python.git", description = "Python programming language"}'
+	'ruby = {repo = "https://github.com/rtx-plugins/rtx-ruby.git", description = "Ruby programming language"}'
+	'tiny = {repo = "https://github.com/rtx-plugins/rtx-tiny.git", description = "Minimal plugin used to test rtx"}'
+)
+custom_names=" go golang java node nodejs pipenv poetry python ruby tiny "
+
+cat >registry.toml <<HEADER
+# This file is generated by scripts/update-registry.sh
+# Do not edit this file manually
+version = 1
+
+[plugins]
+# asdf original shorthands from https://github.com/asdf-vm/asdf-plugins
+HEADER
+for file in asdf-plugins/plugins/*; do
+	plugin=$(basename "$file")
+	if [[ $custom_names == *" $plugin "* ]]; then
+		continue
+	fi
+	repository=$(cat "$file")
+	repository="${repository/#repository = /}"
+	echo "$plugin = {repo = \"$repository\"}" >>registry.toml
+done
+echo "# rtx custom plugins" >>registry.toml
+for plugin in "${custom_plugins[@]}"; do
+	echo "$plugin" >>registry.toml
+done
+rm -rf asdf-plugins
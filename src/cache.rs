@@ -73,6 +73,14 @@ where
         Ok(val)
     }
 
+    /// the cached value even if it is no longer fresh, useful as a fallback when fetching fails
+    pub fn get_stale(&self) -> Option<T> {
+        match self.cache.get() {
+            Some(val) => Some(val.clone()),
+            None => self.parse().ok(),
+        }
+    }
+
     fn parse(&self) -> Result<T> {
         let path = &self.cache_file_path;
         trace!("reading {}", display_path(path));
@@ -1,6 +1,7 @@
 use std::ffi::{OsStr, OsString};
 use std::fmt::Display;
 
+use clap::error::ErrorKind;
 use clap::{Arg, Command, Error};
 use color_eyre::eyre::Result;
 use regex::Regex;
@@ -15,17 +16,17 @@ pub struct ToolArg {
 }
 
 impl ToolArg {
-    pub fn parse(input: &str) -> Self {
-        match input.split_once('@') {
+    pub fn parse(input: &str) -> Result<Self> {
+        Ok(match split_tool_version(input) {
             Some((plugin, version)) => Self {
                 plugin: plugin.to_string(),
-                tvr: Some(ToolVersionRequest::new(plugin.to_string(), version)),
+                tvr: Some(ToolVersionRequest::new(plugin.to_string(), version)?),
             },
             None => Self {
                 plugin: input.into(),
                 tvr: None,
             },
-        }
+        })
     }
 
     /// this handles the case where the user typed in:
@@ -42,7 +43,7 @@ impl ToolArg {
             let a = tools[0].clone();
             let b = tools[1].clone();
             if matches!(a.tvr, None) && matches!(b.tvr, None) && re.is_match(&b.plugin) {
-                tools[1].tvr = Some(ToolVersionRequest::new(a.plugin.clone(), &b.plugin));
+                tools[1].tvr = Some(ToolVersionRequest::Version(a.plugin.clone(), b.plugin));
                 tools[1].plugin = a.plugin;
                 tools.remove(0);
             }
@@ -50,14 +51,22 @@ impl ToolArg {
         tools
     }
 
-    pub fn with_version(self, version: &str) -> Self {
-        Self {
-            tvr: Some(ToolVersionRequest::new(self.plugin.clone(), version)),
+    pub fn with_version(self, version: &str) -> Result<Self> {
+        Ok(Self {
+            tvr: Some(ToolVersionRequest::new(self.plugin.clone(), version)?),
             ..self
-        }
+        })
     }
 }
 
+/// splits "node@20" into ("node", "20")
+/// scoped npm packages like "npm:@antfu/ni@0.21" keep their leading "@"
+fn split_tool_version(input: &str) -> Option<(&str, &str)> {
+    let start = input.find(":@").map_or(0, |i| i + 2);
+    let (plugin, version) = input[start..].split_once('@')?;
+    Some((&input[..start + plugin.len()], version))
+}
+
 impl Display for ToolArg {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self.tvr {
@@ -88,6 +97,62 @@ impl clap::builder::TypedValueParser for ToolArgParser {
         _arg: Option<&Arg>,
         value: OsString,
     ) -> Result<Self::Value, Error> {
-        Ok(ToolArg::parse(&value.to_string_lossy()))
+        ToolArg::parse(&value.to_string_lossy())
+            .map_err(|err| Error::raw(ErrorKind::InvalidValue, format!("{err}\n")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tool_arg_parse() {
+        let arg = ToolArg::parse("node@20").unwrap();
+        assert_eq!(arg.plugin, "node");
+        assert_eq!(arg.tvr.unwrap().version(), "20");
+
+        let arg = ToolArg::parse("npm:@antfu/ni@0.21").unwrap();
+        assert_eq!(arg.plugin, "npm:@antfu/ni");
+        assert_eq!(arg.tvr.unwrap().version(), "0.21");
+
+        let arg = ToolArg::parse("npm:@antfu/ni").unwrap();
+        assert_eq!(arg.plugin, "npm:@antfu/ni");
+        assert!(arg.tvr.is_none());
+    }
+
+    #[test]
+    fn test_tool_arg_parse_range() {
+        let arg = ToolArg::parse("node@>=18 <20").unwrap();
+        assert_eq!(
+            arg.tvr.unwrap(),
+            ToolVersionRequest::Range("node".into(), ">=18 <20".into())
+        );
+
+        let err = ToolArg::parse("node@^x").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid version range ^x: unexpected character 'x' while parsing major version number"
+        );
+
+        let err = ToolArg::parse("node@foo:20").unwrap_err();
+        assert_eq!(err.to_string(), "invalid tool version request: foo:20");
+    }
+
+    #[test]
+    fn test_tool_arg_parse_sub() {
+        let tvr = ToolArg::parse("node@sub-0.1:20").unwrap().tvr.unwrap();
+        assert_eq!(
+            tvr,
+            ToolVersionRequest::Sub {
+                plugin_name: "node".into(),
+                sub: "0.1".into(),
+                orig_version: "20".into(),
+            }
+        );
+        assert_eq!(tvr.to_string(), "node@sub-0.1:20");
+
+        let err = ToolArg::parse("node@sub-x:latest").unwrap_err();
+        assert_eq!(err.to_string(), "invalid version offset: x");
     }
 }
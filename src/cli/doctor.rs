@@ -1,8 +1,10 @@
 use std::fmt::Write;
 use std::process::exit;
+use std::time::Duration;
 
 use color_eyre::eyre::Result;
 use console::{pad_str, style, Alignment};
+use humantime::format_duration;
 use indenter::indented;
 
 use crate::build_time::built_info;
@@ -50,6 +52,11 @@ impl Command for Doctor {
                 checks.push(format!("plugin {} is not installed", &plugin.name));
                 continue;
             }
+            if let Some((_, status)) = plugin.last_update() {
+                if let Some(err) = status.strip_prefix("failed: ") {
+                    checks.push(format!("plugin {} failed to update: {err}", &plugin.name));
+                }
+            }
         }
 
         if let Some(latest) = cli::version::check_for_new_version(duration::HOURLY) {
@@ -124,7 +131,18 @@ fn render_plugins(config: &Config) -> String {
                         let sha = git
                             .current_sha_short()
                             .unwrap_or_else(|_| "(unknown)".to_string());
-                        format!("  {padded_name} {url}#{sha}\n")
+                        let last_update = match p.last_update() {
+                            Some((age, status)) => {
+                                let status = match status.starts_with("failed: ") {
+                                    true => "update failed",
+                                    false => &status,
+                                };
+                                let age = Duration::from_secs(age.as_secs());
+                                format!(" ({status} {} ago)", format_duration(age))
+                            }
+                            None => String::new(),
+                        };
+                        format!("  {padded_name} {url}#{sha}{last_update}\n")
                     }
                     None => format!("  {padded_name}\n"),
                 }
@@ -130,6 +130,9 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
   # Specify command as a string:
   $ <bold>rtx exec node@20 python@3.11 --command "node -v && python -V"</bold>
 
+  # Use the highest installed version that satisfies a semver range:
+  $ <bold>rtx exec 'node@>=18 <<20' -- node ./app.js</bold>
+
   # Run a command in a different directory:
   $ <bold>rtx x -C /path/to/project node@20 -- node ./app.js</bold>
 "#
@@ -8,6 +8,7 @@ use rayon::ThreadPoolBuilder;
 
 use crate::cli::args::tool::{ToolArg, ToolArgParser};
 use crate::cli::command::Command;
+use crate::config::lockfile::{apply_lockfiles, update_lockfiles};
 use crate::config::Config;
 use crate::config::MissingRuntimeBehavior::AutoInstall;
 
@@ -42,6 +43,10 @@ pub struct Install {
     #[clap(long, short, requires = "tool")]
     force: bool,
 
+    /// Install the versions and plugin commits pinned in .rtx.lock files
+    #[clap(long, conflicts_with = "tool")]
+    locked: bool,
+
     /// Show installation output
     #[clap(long, short, action = clap::ArgAction::Count)]
     verbose: u8,
@@ -53,6 +58,7 @@ impl Command for Install {
 
         match &self.tool {
             Some(runtime) => self.install_runtimes(config, runtime)?,
+            None if self.locked => self.install_locked_runtimes(config)?,
             None => self.install_missing_runtimes(config)?,
         }
 
@@ -131,6 +137,7 @@ impl Install {
                     return Err(err)?;
                 }
             }
+            plugin.autoupdate(config);
             let tv = tvr.resolve(config, &plugin, opts, ts.latest_versions)?;
             tool_versions.push((plugin, tv));
         }
@@ -141,11 +148,24 @@ impl Install {
         let mut ts = ToolsetBuilder::new()
             .with_latest_versions()
             .build(&mut config)?;
+        for plugin in ts.versions.keys().filter_map(|p| config.tools.get(p)) {
+            plugin.autoupdate(&config);
+        }
         if ts.list_missing_versions(&config).is_empty() {
             warn!("no runtimes to install");
         }
         let mpr = MultiProgressReport::new(config.settings.verbose);
         ts.install_missing(&mut config, mpr)?;
+        update_lockfiles(&config, &ts)?;
+
+        Ok(())
+    }
+
+    fn install_locked_runtimes(&self, mut config: Config) -> Result<()> {
+        let mut ts = ToolsetBuilder::new().build_unresolved(&config)?;
+        apply_lockfiles(&mut config, &mut ts)?;
+        let mpr = MultiProgressReport::new(config.settings.verbose);
+        ts.install_missing(&mut config, mpr)?;
 
         Ok(())
     }
@@ -239,6 +259,7 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
   $ <bold>rtx install node@20</bold>      # install fuzzy node version
   $ <bold>rtx install node</bold>         # install version specified in .tool-versions or .rtx.toml
   $ <bold>rtx install</bold>                # installs everything specified in .tool-versions or .rtx.toml
+  $ <bold>rtx install --locked</bold>       # installs the versions pinned in .rtx.lock files
 "#
 );
 
@@ -246,7 +267,7 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
 mod tests {
     use pretty_assertions::assert_str_eq;
 
-    use crate::{assert_cli, assert_cli_snapshot, dirs};
+    use crate::{assert_cli, assert_cli_err, assert_cli_snapshot, dirs};
 
     #[test]
     fn test_install_force() {
@@ -276,6 +297,13 @@ mod tests {
         assert_cli!("global", "--unset", "dummy");
     }
 
+    #[test]
+    fn test_install_locked_without_lockfile() {
+        let err = assert_cli_err!("install", "--locked");
+        // only the project config needs a lockfile, not ~/.test-tool-versions
+        assert_str_eq!(err.to_string(), "no lockfile found at ~/cwd/.rtx.lock");
+    }
+
     #[test]
     fn test_install_nothing() {
         // this doesn't do anything since dummy isn't specified
@@ -23,7 +23,7 @@ pub struct Latest {
 }
 
 impl Command for Latest {
-    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+    fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
         let mut prefix = match self.tool.tvr {
             None => self.asdf_version,
             Some(ToolVersionRequest::Version(_, version)) => Some(version),
@@ -32,20 +32,22 @@ impl Command for Latest {
                 style(&self.tool).cyan().for_stderr()
             ))?,
         };
-        let plugin = config.tools.get(&self.tool.plugin).ok_or_else(|| {
-            eyre!(
+        let plugin = config.get_or_create_tool(&self.tool.plugin);
+        if !plugin.is_installed() {
+            Err(eyre!(
                 "plugin {} not found. run {} to install it",
                 style(self.tool.plugin.to_string()).cyan().for_stderr(),
                 style(format!("rtx plugin install {}", self.tool.plugin))
                     .yellow()
                     .for_stderr()
-            )
-        })?;
+            ))?;
+        }
+        plugin.autoupdate(&config);
         if let Some(v) = prefix {
             prefix = Some(config.resolve_alias(&plugin.name, &v)?);
         }
 
-        if let Some(version) = plugin.latest_version(&config.settings, prefix)? {
+        if let Some(version) = plugin.latest_version(&config, &config.settings, prefix)? {
             rtxprintln!(out, "{}", version);
         }
         Ok(())
@@ -1,4 +1,5 @@
 use color_eyre::eyre::Result;
+use console::style;
 use std::sync::Arc;
 
 use crate::cli::args::tool::{ToolArg, ToolArgParser};
@@ -15,6 +16,7 @@ use crate::ui::prompt;
 ///
 /// note that the results are cached for 24 hours
 /// run `rtx cache clean` to clear the cache and get fresh results
+/// prereleases are labeled when the output is a terminal
 #[derive(Debug, clap::Args)]
 #[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP, aliases = ["list-all", "list-remote"])]
 pub struct LsRemote {
@@ -31,13 +33,14 @@ pub struct LsRemote {
 impl Command for LsRemote {
     fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
         let plugin = self.get_plugin(&mut config)?;
+        plugin.autoupdate(&config);
 
         let prefix = match &self.plugin.tvr {
             Some(ToolVersionRequest::Version(_, v)) => Some(v),
             _ => self.prefix.as_ref(),
         };
 
-        let versions = plugin.list_remote_versions(&config.settings)?;
+        let versions = plugin.list_remote_versions(&config)?;
         let versions = match prefix {
             Some(prefix) => versions
                 .into_iter()
@@ -46,14 +49,22 @@ impl Command for LsRemote {
             None => versions,
         };
 
-        for version in versions {
-            rtxprintln!(out, "{}", version);
-        }
+        // the label is left out when piped so the output can still be used in scripts
+        print_versions(out, &plugin, versions, console::user_attended());
 
         Ok(())
     }
 }
 
+fn print_versions(out: &mut Output, plugin: &Tool, versions: Vec<String>, label: bool) {
+    for version in versions {
+        match label && plugin.is_prerelease(&version) {
+            true => rtxprintln!(out, "{} {}", version, style("(prerelease)").dim()),
+            false => rtxprintln!(out, "{}", version),
+        }
+    }
+}
+
 impl LsRemote {
     fn get_plugin(&self, config: &mut Config) -> Result<Arc<Tool>> {
         let plugin_name = self.plugin.plugin.clone();
@@ -98,7 +109,13 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
 
 #[cfg(test)]
 mod tests {
+    use insta::assert_snapshot;
+
     use crate::assert_cli_snapshot;
+    use crate::config::Config;
+    use crate::output::Output;
+
+    use super::print_versions;
 
     #[test]
     fn test_list_remote() {
@@ -110,4 +127,17 @@ mod tests {
         assert_cli_snapshot!("list-remote", "dummy", "1");
         assert_cli_snapshot!("list-remote", "dummy@2");
     }
+
+    #[test]
+    fn test_ls_remote_prerelease_label() {
+        let config = Config::load().unwrap();
+        let tool = config.tools.get("dummy").unwrap();
+        let versions = vec!["1.0.0".into(), "2.0.0-rc.1".into()];
+        let mut out = Output::tracked();
+        print_versions(&mut out, tool, versions, true);
+        assert_snapshot!(console::strip_ansi_codes(&out.stdout.content), @r###"
+        1.0.0
+        2.0.0-rc.1 (prerelease)
+        "###);
+    }
 }
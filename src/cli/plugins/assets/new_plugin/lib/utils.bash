@@ -0,0 +1,47 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+TOOL_NAME="{{ name }}"
+GIT_URL="{{ repo }}"
+# the versions are the tags that start with this prefix, e.g.: "v" for v1.0.0
+TAG_PREFIX="{{ tag_prefix }}"
+
+fail() {
+  echo "rtx-$TOOL_NAME: $*" >&2
+  exit 1
+}
+
+# sorts versions like 1.10.0 after 1.9.0
+sort_versions() {
+  sed 'h; s/[+-]/./g; s/.p\([[:digit:]]\)/.z\1/; s/$/.z/; G; s/\n/ /' |
+    LC_ALL=C sort -t. -k 1,1 -k 2,2n -k 3,3n -k 4,4n -k 5,5n | awk '{print $2}'
+}
+
+# the versions are the tags of the repository without $TAG_PREFIX
+list_all_versions() {
+  git ls-remote --tags --refs "$GIT_URL" | sed 's|.*refs/tags/||' |
+    awk -v prefix="$TAG_PREFIX" 'index($0, prefix) == 1 { print substr($0, length(prefix) + 1) }' |
+    sort_versions
+}
+{%- if template != "source" %}
+
+get_os() {
+  uname -s | tr '[:upper:]' '[:lower:]'
+}
+
+get_arch() {
+  case "$(uname -m)" in
+  x86_64 | amd64) echo "x86_64" ;;
+  aarch64 | arm64) echo "aarch64" ;;
+  *) uname -m ;;
+  esac
+}
+
+# TODO: check that this matches the names of the archives that are published
+download_url() {
+  local version="$1" os arch
+  os="$(get_os)"
+  arch="$(get_arch)"
+  echo "{{ url }}"
+}
+{%- endif %}
@@ -0,0 +1,100 @@
+use color_eyre::eyre::{eyre, Result};
+use console::style;
+
+use crate::cli::args::tool::{ToolArg, ToolArgParser};
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::errors::Error::PluginNotInstalled;
+use crate::output::Output;
+
+/// Shows the documentation of a plugin
+///
+/// External plugins provide this with the asdf help scripts:
+/// bin/help.overview, help.deps, help.config and help.links
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
+pub struct PluginsHelp {
+    /// Plugin to show the documentation of
+    /// e.g.: node
+    /// if "@<VERSION>" is specified, the documentation for that version is shown
+    #[clap(value_parser = ToolArgParser, verbatim_doc_comment)]
+    tool: ToolArg,
+
+    /// the version to show the documentation for
+    /// same as the first argument after the "@"
+    /// used for asdf compatibility
+    #[clap(hide = true, verbatim_doc_comment)]
+    asdf_version: Option<String>,
+}
+
+impl Command for PluginsHelp {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        let tool = match config.tools.get(&self.tool.plugin) {
+            Some(tool) if tool.is_installed() => tool,
+            _ => Err(PluginNotInstalled(self.tool.plugin.clone()))?,
+        };
+        let tool_arg = match &self.asdf_version {
+            Some(version) => self.tool.with_version(version)?,
+            None => self.tool,
+        };
+        let tv = match &tool_arg.tvr {
+            Some(tvr) => Some(tvr.resolve(&config, tool, Default::default(), false)?),
+            None => None,
+        };
+        let help = tool
+            .plugin
+            .help(&config, tv.as_ref())?
+            .ok_or_else(|| eyre!("{} does not have any documentation", tool.name))?;
+
+        rtxprintln!(out, "{}", help.overview);
+        let sections = [
+            ("Dependencies", help.deps),
+            ("Configuration", help.config),
+            ("Links", help.links),
+        ];
+        for (title, section) in sections {
+            if let Some(section) = section {
+                rtxprintln!(out, "\n{}\n{}", style(title).bold().underlined(), section);
+            }
+        }
+        Ok(())
+    }
+}
+
+static AFTER_LONG_HELP: &str = color_print::cstr!(
+    r#"<bold><underline>Examples:</underline></bold>
+  $ <bold>rtx plugins help node</bold>
+  $ <bold>rtx plugins help node@20</bold>  # documentation specific to node-20.x
+"#
+);
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use crate::{assert_cli, assert_cli_err};
+
+    #[test]
+    fn test_plugins_help() {
+        let stdout = assert_cli!("plugins", "help", "dummy@1.0.0");
+        assert_snapshot!(stdout, @r###"
+        Dummy plugin documentation
+
+        Dummy plugin is a plugin only used for unit tests
+
+        Details specific for version 1.0.0
+        "###);
+    }
+
+    #[test]
+    fn test_plugins_help_core() {
+        let stdout = assert_cli!("plugins", "help", "node");
+        assert!(stdout.contains("https://github.com/jdxcode/rtx/blob/main/docs/node.md"));
+    }
+
+    #[test]
+    fn test_plugins_help_no_docs() {
+        let err = assert_cli_err!("plugins", "help", "tiny");
+        assert_eq!(err.to_string(), "tiny does not have any documentation");
+    }
+}
@@ -60,7 +60,7 @@ impl Command for PluginsLink {
     }
 }
 
-fn get_name_from_path(path: &Path) -> String {
+pub fn get_name_from_path(path: &Path) -> String {
     let name = path.file_name().unwrap().to_str().unwrap();
     let name = name.strip_prefix("asdf-").unwrap_or(name);
     let name = name.strip_prefix("rtx-").unwrap_or(name);
@@ -34,6 +34,7 @@ impl Command for PluginsLs {
             return PluginsLsRemote {
                 urls: self.urls,
                 only_names: false,
+                search: None,
             }
             .run(config, out);
         }
@@ -1,12 +1,13 @@
 use std::collections::HashSet;
 
 use color_eyre::eyre::Result;
-use console::{measure_text_width, pad_str, Alignment};
+use console::{measure_text_width, pad_str, style, Alignment};
 use itertools::Itertools;
 
 use crate::cli::command::Command;
 use crate::config::Config;
 use crate::output::Output;
+use crate::registry::{RegistryBackend, REGISTRY};
 
 /// List all available remote plugins
 #[derive(Debug, clap::Args)]
@@ -21,6 +22,10 @@ pub struct PluginsLsRemote {
     /// by default it will show a "*" next to installed plugins
     #[clap(long)]
     pub only_names: bool,
+
+    /// Only show plugins with this term in their name or description
+    #[clap(long, short)]
+    pub search: Option<String>,
 }
 
 impl Command for PluginsLsRemote {
@@ -32,26 +37,69 @@ impl Command for PluginsLsRemote {
             .map(|p| p.name.clone())
             .collect::<HashSet<_>>();
 
-        let shorthands = config.get_shorthands().iter().sorted().collect_vec();
-        let max_plugin_len = shorthands
+        let mut plugins = config
+            .get_shorthands()
             .iter()
-            .map(|(plugin, _)| measure_text_width(plugin))
-            .max()
-            .unwrap_or(0);
-
-        if shorthands.is_empty() {
+            .map(|(plugin, repo)| {
+                let description = REGISTRY.get(plugin).and_then(|p| p.description.as_deref());
+                (plugin.to_string(), repo.as_str(), description)
+            })
+            .collect_vec();
+        if !config.settings.disable_default_shorthands {
+            plugins.extend(
+                REGISTRY
+                    .plugins
+                    .iter()
+                    .filter(|(_, p)| p.backend != RegistryBackend::Asdf)
+                    .map(|(name, p)| {
+                        (p.tool_name(name), p.repo.as_str(), p.description.as_deref())
+                    }),
+            );
+        }
+        if plugins.is_empty() {
             warn!("default shorthands are disabled");
         }
+        if let Some(search) = &self.search {
+            let search = search.to_lowercase();
+            plugins.retain(|(plugin, _, description)| {
+                plugin.to_lowercase().contains(&search)
+                    || description.map_or(false, |d| d.to_lowercase().contains(&search))
+            });
+        }
+        plugins.sort();
+
+        let max_plugin_len = plugins
+            .iter()
+            .map(|(plugin, _, _)| measure_text_width(plugin))
+            .max()
+            .unwrap_or(0);
+        let max_url_len = plugins
+            .iter()
+            .map(|(_, repo, _)| measure_text_width(repo))
+            .max()
+            .unwrap_or(0);
 
-        for (plugin, repo) in shorthands {
-            let installed = if !self.only_names && installed_plugins.contains(plugin) {
+        for (plugin, repo, description) in plugins {
+            let installed = if !self.only_names && installed_plugins.contains(&plugin) {
                 "*"
             } else {
                 " "
             };
-            let url = if self.urls { repo } else { "" };
-            let plugin = pad_str(plugin, max_plugin_len, Alignment::Left, None);
-            rtxprintln!(out, "{} {}{}", plugin, installed, url);
+            let mut line = format!(
+                "{} {}",
+                pad_str(&plugin, max_plugin_len, Alignment::Left, None),
+                installed
+            );
+            if self.urls {
+                line.push_str(&pad_str(repo, max_url_len, Alignment::Left, None));
+            }
+            match description {
+                Some(description) if !self.only_names => {
+                    line = format!("{line} {}", style(description).dim());
+                }
+                _ => {}
+            }
+            rtxprintln!(out, "{}", line.trim_end());
         }
 
         Ok(())
@@ -61,10 +109,12 @@ impl Command for PluginsLsRemote {
 const LONG_ABOUT: &str = r#"
 List all available remote plugins
 
-The full list is here: https://github.com/jdxcode/rtx/blob/main/src/default_shorthands.rs
+The full list is here: https://github.com/jdxcode/rtx/blob/main/registry.toml
+Run `rtx plugins registry update` to get plugins added since this version of rtx was released.
 
 Examples:
   $ rtx plugins ls-remote
+  $ rtx plugins ls-remote --search python
 "#;
 
 #[cfg(test)]
@@ -5,15 +5,19 @@ use crate::cli::command::Command;
 use crate::config::Config;
 use crate::output::Output;
 
+mod help;
 mod install;
 mod link;
 mod ls;
 mod ls_remote;
+mod new;
+mod registry;
+mod test;
 mod uninstall;
 mod update;
 
 #[derive(Debug, clap::Args)]
-#[clap(about = "Manage plugins", visible_alias = "p", aliases = ["plugin", "plugin-list"])]
+#[clap(about = "Manage plugins", visible_alias = "p", aliases = ["plugin", "plugin-list"], disable_help_subcommand = true)]
 pub struct Plugins {
     #[clap(subcommand)]
     command: Option<Commands>,
@@ -38,10 +42,14 @@ pub struct Plugins {
 
 #[derive(Debug, Subcommand)]
 enum Commands {
+    Help(help::PluginsHelp),
     Install(install::PluginsInstall),
     Link(link::PluginsLink),
     Ls(ls::PluginsLs),
     LsRemote(ls_remote::PluginsLsRemote),
+    New(new::PluginsNew),
+    Registry(registry::PluginsRegistry),
+    Test(test::PluginsTest),
     Uninstall(uninstall::PluginsUninstall),
     Update(update::Update),
 }
@@ -49,10 +57,14 @@ enum Commands {
 impl Commands {
     pub fn run(self, config: Config, out: &mut Output) -> Result<()> {
         match self {
+            Self::Help(cmd) => cmd.run(config, out),
             Self::Install(cmd) => cmd.run(config, out),
             Self::Link(cmd) => cmd.run(config, out),
             Self::Ls(cmd) => cmd.run(config, out),
             Self::LsRemote(cmd) => cmd.run(config, out),
+            Self::New(cmd) => cmd.run(config, out),
+            Self::Registry(cmd) => cmd.run(config, out),
+            Self::Test(cmd) => cmd.run(config, out),
             Self::Uninstall(cmd) => cmd.run(config, out),
             Self::Update(cmd) => cmd.run(config, out),
         }
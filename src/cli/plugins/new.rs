@@ -0,0 +1,238 @@
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+
+use clap::ValueHint;
+use color_eyre::eyre::{eyre, Result};
+use console::style;
+use tera::{Context, Tera};
+
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::file::{create_dir_all, display_path};
+use crate::output::Output;
+
+/// Creates a new plugin from a template
+///
+/// The plugin has the asdf scripts bin/list-all, bin/download and bin/install,
+/// the optional bin/list-bin-paths and bin/exec-env, an rtx.plugin.toml and a
+/// test/.tool-versions fixture. The versions are the tags of the tool's git repository.
+/// It can be used right away with `rtx plugins link`.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
+pub struct PluginsNew {
+    /// The name of the plugin
+    /// e.g.: ripgrep
+    #[clap(verbatim_doc_comment)]
+    name: String,
+
+    /// The directory to create the plugin in
+    /// defaults to ./rtx-<NAME>
+    #[clap(value_hint = ValueHint::DirPath, verbatim_doc_comment)]
+    path: Option<PathBuf>,
+
+    /// The git repository of the tool, its tags are the versions
+    /// e.g.: https://github.com/BurntSushi/ripgrep or BurntSushi/ripgrep for GitHub
+    #[clap(long, verbatim_doc_comment)]
+    repo: String,
+
+    /// The prefix of the tags that are versions, it is removed from the version
+    /// e.g.: "jq-" for jq-1.7.1, "" for tags without a prefix
+    #[clap(long, default_value = "v", verbatim_doc_comment)]
+    tag_prefix: String,
+
+    /// How the plugin installs the tool
+    #[clap(long, value_enum, default_value_t = Template::Github)]
+    template: Template,
+
+    /// The url of the .tar.gz to install, can use {{version}}, {{os}} and {{arch}}
+    /// defaults to the GitHub release <NAME>-<TAG>-{{os}}-{{arch}}.tar.gz
+    #[clap(long, required_if_eq("template", "tarball"), verbatim_doc_comment)]
+    url: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Template {
+    /// downloads a GitHub release asset
+    Github,
+    /// downloads a .tar.gz from any url
+    Tarball,
+    /// clones the repository and builds it
+    Source,
+}
+
+macro_rules! asset {
+    ($path:literal) => {
+        ($path, include_str!(concat!("assets/new_plugin/", $path)))
+    };
+}
+
+/// the templates of the files in the plugin by their path, the ones in bin/ are executable
+const FILES: [(&str, &str); 9] = [
+    asset!("bin/list-all"),
+    asset!("bin/download"),
+    asset!("bin/install"),
+    asset!("bin/list-bin-paths"),
+    asset!("bin/exec-env"),
+    asset!("lib/utils.bash"),
+    asset!("rtx.plugin.toml"),
+    asset!("test/.tool-versions"),
+    asset!("README.md"),
+];
+
+impl Command for PluginsNew {
+    fn run(self, _config: Config, out: &mut Output) -> Result<()> {
+        let path = self
+            .path
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(format!("rtx-{}", self.name)));
+        if path.exists() {
+            return Err(eyre!("{} already exists", display_path(&path)));
+        }
+        let ctx = self.context()?;
+        for (name, template) in FILES {
+            let file = path.join(name);
+            create_dir_all(file.parent().unwrap())?;
+            fs::write(&file, Tera::one_off(template, &ctx, false)?)?;
+            if name.starts_with("bin/") {
+                fs::set_permissions(&file, fs::Permissions::from_mode(0o755))?;
+            }
+        }
+        rtxprintln!(
+            out,
+            "created {} in {}, link it with: {}",
+            style(&self.name).cyan(),
+            display_path(&path),
+            style(format!(
+                "rtx plugins link {} {}",
+                self.name,
+                display_path(&path)
+            ))
+            .bold()
+        );
+        Ok(())
+    }
+}
+
+impl PluginsNew {
+    fn context(&self) -> Result<Context> {
+        let repo = match self.repo.contains("://") || self.repo.starts_with("git@") {
+            true => self.repo.clone(),
+            false => format!("https://github.com/{}", self.repo),
+        };
+        if self.template == Template::Github
+            && self.url.is_none()
+            && !repo.starts_with("https://github.com/")
+        {
+            return Err(eyre!("{} is not a GitHub repository", repo));
+        }
+        let url = match &self.url {
+            Some(url) => url.clone(),
+            None if self.template == Template::Github => format!(
+                "{repo}/releases/download/{tag}/{name}-{tag}-{{{{os}}}}-{{{{arch}}}}.tar.gz",
+                repo = repo.trim_end_matches(".git"),
+                tag = format!("{}{{{{version}}}}", self.tag_prefix),
+                name = self.name
+            ),
+            None => String::new(),
+        };
+        let mut ctx = Context::new();
+        ctx.insert("name", &self.name);
+        ctx.insert("repo", &repo);
+        ctx.insert("tag_prefix", &self.tag_prefix);
+        ctx.insert("url", &bash_url(&url));
+        ctx.insert("env_prefix", &self.name.to_uppercase().replace('-', "_"));
+        ctx.insert("template", &format!("{:?}", self.template).to_lowercase());
+        Ok(ctx)
+    }
+}
+
+/// the download url in lib/utils.bash uses shell variables instead of the template syntax
+/// e.g.: "{{version}}" -> "${version}"
+fn bash_url(url: &str) -> String {
+    regex!(r"\{\{\s*(version|os|arch)\s*\}\}")
+        .replace_all(url, "$${$1}")
+        .to_string()
+}
+
+static AFTER_LONG_HELP: &str = color_print::cstr!(
+    r#"<bold><underline>Examples:</underline></bold>
+  # installs the GitHub release assets of BurntSushi/ripgrep, its tags have no "v" prefix
+  $ <bold>rtx plugins new ripgrep --repo BurntSushi/ripgrep --tag-prefix '' \
+      --url 'https://github.com/BurntSushi/ripgrep/releases/download/{{version}}/ripgrep-{{version}}-{{arch}}-unknown-linux-musl.tar.gz'</bold>
+  $ <bold>rtx plugins link ripgrep ./rtx-ripgrep</bold>
+
+  # builds zstd from source with make
+  $ <bold>rtx plugins new zstd --repo facebook/zstd --template source</bold>
+"#
+);
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use insta::assert_snapshot;
+
+    use crate::assert_cli;
+    use crate::file::{is_executable, remove_all};
+    use crate::plugins::RtxPluginToml;
+
+    use super::*;
+
+    #[test]
+    fn test_plugins_new() {
+        let path = Path::new("../data/rtx-newtool");
+        let _ = remove_all(path);
+        assert_cli!(
+            "plugins",
+            "new",
+            "newtool",
+            "../data/rtx-newtool",
+            "--repo",
+            "rtx-plugins/newtool"
+        );
+        assert!(is_executable(&path.join("bin/install")));
+        assert!(!is_executable(&path.join("rtx.plugin.toml")));
+        assert!(RtxPluginToml::from_file(&path.join("rtx.plugin.toml")).is_ok());
+        let utils = fs::read_to_string(path.join("lib/utils.bash")).unwrap();
+        assert!(utils.contains(r#"GIT_URL="https://github.com/rtx-plugins/newtool""#));
+        assert!(utils.contains(r#"TAG_PREFIX="v""#));
+        assert!(utils.contains(r#"echo "https://github.com/rtx-plugins/newtool/releases/download/v${version}/newtool-v${version}-${os}-${arch}.tar.gz""#));
+        assert_snapshot!(fs::read_to_string(path.join("test/.tool-versions")).unwrap(), @"newtool latest");
+        remove_all(path).unwrap();
+    }
+
+    #[test]
+    fn test_plugins_new_source() {
+        let path = Path::new("../data/rtx-newtool-src");
+        let _ = remove_all(path);
+        assert_cli!(
+            "plugins",
+            "new",
+            "newtool-src",
+            "../data/rtx-newtool-src",
+            "--repo",
+            "https://example.com/newtool.git",
+            "--template",
+            "source",
+            "--tag-prefix",
+            "newtool-"
+        );
+        let utils = fs::read_to_string(path.join("lib/utils.bash")).unwrap();
+        assert!(!utils.contains("download_url"));
+        assert!(utils.contains(r#"TAG_PREFIX="newtool-""#));
+        let install = fs::read_to_string(path.join("bin/install")).unwrap();
+        assert!(install.contains("make install"));
+        let exec_env = fs::read_to_string(path.join("bin/exec-env")).unwrap();
+        assert!(exec_env.contains("export NEWTOOL_SRC_HOME="));
+        remove_all(path).unwrap();
+    }
+
+    #[test]
+    fn test_bash_url() {
+        assert_eq!(
+            bash_url("https://example.com/{{version}}/foo-{{ os }}-{{arch}}.tar.gz"),
+            "https://example.com/${version}/foo-${os}-${arch}.tar.gz"
+        );
+    }
+}
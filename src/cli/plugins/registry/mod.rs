@@ -0,0 +1,34 @@
+use clap::Subcommand;
+use color_eyre::eyre::Result;
+
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::output::Output;
+
+mod update;
+
+#[derive(Debug, clap::Args)]
+#[clap(about = "Manage the registry of plugin shorthands")]
+pub struct PluginsRegistry {
+    #[clap(subcommand)]
+    command: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    Update(update::RegistryUpdate),
+}
+
+impl Commands {
+    pub fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        match self {
+            Self::Update(cmd) => cmd.run(config, out),
+        }
+    }
+}
+
+impl Command for PluginsRegistry {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        self.command.run(config, out)
+    }
+}
@@ -0,0 +1,69 @@
+use std::fs;
+
+use color_eyre::eyre::{eyre, Result};
+
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::env::RTX_REGISTRY_URL;
+use crate::file::display_path;
+use crate::output::Output;
+use crate::registry::{registry_path, Registry};
+use crate::{dirs, file, http};
+
+/// Downloads the latest registry of plugin shorthands
+///
+/// The registry is downloaded from RTX_REGISTRY_URL unless a url or path is given.
+/// It replaces the registry bundled with rtx, `shorthands_file` is still applied on top of it.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
+pub struct RegistryUpdate {
+    /// The url or path of the registry file to use
+    #[clap()]
+    source: Option<String>,
+}
+
+impl Command for RegistryUpdate {
+    fn run(self, _config: Config, out: &mut Output) -> Result<()> {
+        let source = self.source.unwrap_or_else(|| RTX_REGISTRY_URL.clone());
+        let body = match source.contains("://") {
+            true => http::Client::new()?.get_text(&source)?,
+            false => {
+                let path = dirs::CURRENT.join(file::replace_path(&source));
+                fs::read_to_string(&path)
+                    .map_err(|err| eyre!("failed to read {}: {err}", display_path(&path)))?
+            }
+        };
+        let registry = Registry::parse(&body)?;
+        let path = registry_path();
+        file::create_dir_all(path.parent().unwrap())?;
+        fs::write(&path, body)?;
+        rtxprintln!(
+            out,
+            "updated plugin registry to {} plugins",
+            registry.plugins.len()
+        );
+        Ok(())
+    }
+}
+
+static AFTER_LONG_HELP: &str = color_print::cstr!(
+    r#"<bold><underline>Examples:</underline></bold>
+  $ <bold>rtx plugins registry update</bold>
+  $ <bold>rtx plugins registry update ~/src/my-registry.toml</bold>
+"#
+);
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::assert_cli;
+    use crate::registry::registry_path;
+
+    #[test]
+    fn test_registry_update() {
+        let stdout = assert_cli!("plugins", "registry", "update", "../../registry.toml");
+        assert!(stdout.starts_with("updated plugin registry to"));
+        fs::remove_file(registry_path()).unwrap();
+    }
+}
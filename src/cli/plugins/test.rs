@@ -0,0 +1,272 @@
+use std::path::Path;
+use std::process::exit;
+use std::sync::Arc;
+
+use color_eyre::eyre::{eyre, Result};
+use console::style;
+use itertools::Itertools;
+
+use crate::cli::command::Command;
+use crate::cli::plugins::install::get_name_from_url;
+use crate::cli::plugins::link::get_name_from_path;
+use crate::cmd::cmd;
+use crate::config::Config;
+use crate::file::{create_dir_all, display_path, make_symlink, remove_all};
+use crate::output::Output;
+use crate::plugins::ExternalPlugin;
+use crate::tool::Tool;
+use crate::toolset::{ToolVersion, ToolVersionRequest};
+use crate::ui::multi_progress_report::MultiProgressReport;
+use crate::{dirs, env, file};
+
+/// Tests a plugin by installing it and a version of its tool
+///
+/// The plugin is installed into a temporary data directory, so this does not
+/// affect the plugins and tools that are already installed.
+/// The following steps are run, if a step fails the rest are skipped:
+///   install the plugin, list-all, resolve the version, install it,
+///   check the bin paths, run the test command and uninstall the version
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP)]
+pub struct PluginsTest {
+    /// The plugin to test
+    /// a name from the registry, a git url or the path to a local plugin
+    #[clap(verbatim_doc_comment)]
+    plugin: String,
+
+    /// The version of the tool to install
+    #[clap(long, default_value = "latest")]
+    tool_version: String,
+
+    /// Command to run with the installed version to check that it works
+    /// e.g.: `rtx plugins test node -- node --version`
+    #[clap(last = true, verbatim_doc_comment)]
+    command: Vec<String>,
+
+    /// set when rtx runs itself inside of the temporary data directory
+    #[clap(long, hide = true)]
+    isolated: bool,
+}
+
+const STEPS: [&str; 7] = [
+    "install plugin",
+    "list-all",
+    "resolve version",
+    "install version",
+    "bin paths",
+    "test command",
+    "uninstall version",
+];
+
+impl Command for PluginsTest {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        if !self.isolated {
+            return self.run_isolated();
+        }
+        let mut report = Report::default();
+        if self.run_steps(config, out, &mut report).is_err() {
+            for name in &STEPS[report.completed + 1..] {
+                rtxprintln!(out, "{} {}", style("-").dim(), style(name).dim());
+            }
+            return Err(eyre!("plugin test failed at {}", STEPS[report.completed]));
+        }
+        Ok(())
+    }
+}
+
+impl PluginsTest {
+    /// runs rtx again with the data, cache and config directories in a new temporary directory
+    fn run_isolated(&self) -> Result<()> {
+        let dir = env::RTX_TMP_DIR.join(format!("plugins-test-{}", std::process::id()));
+        let config_dir = dir.join("config");
+        create_dir_all(&config_dir)?;
+        let plugin = match Path::new(&self.plugin) {
+            path if path.is_dir() => path.canonicalize()?.to_string_lossy().to_string(),
+            _ => self.plugin.clone(),
+        };
+        let mut args = vec![
+            "plugins".into(),
+            "test".into(),
+            "--isolated".into(),
+            "--tool-version".into(),
+            self.tool_version.clone(),
+            plugin,
+        ];
+        if !self.command.is_empty() {
+            args.push("--".into());
+            args.extend(self.command.clone());
+        }
+        let output = cmd(&*env::RTX_EXE, args)
+            .dir(&dir)
+            .env("RTX_DATA_DIR", &dir)
+            .env("RTX_CACHE_DIR", dir.join("cache"))
+            .env("RTX_CONFIG_DIR", &config_dir)
+            .env("RTX_CONFIG_FILE", config_dir.join("config.toml"))
+            .unchecked()
+            .run();
+        remove_all(&dir)?;
+        let status = output?.status;
+        if !status.success() {
+            // the error has already been displayed by the isolated rtx
+            exit(status.code().unwrap_or(1));
+        }
+        Ok(())
+    }
+
+    fn run_steps(&self, mut config: Config, out: &mut Output, report: &mut Report) -> Result<()> {
+        let mpr = MultiProgressReport::new(config.settings.verbose);
+        let tool = report.step(out, || {
+            let tool = self.build_tool(&mut config)?;
+            if !tool.is_installed() {
+                let mut pr = mpr.add();
+                tool.install(&config, &mut pr, false)?;
+            }
+            let source = match tool.plugin_path.read_link() {
+                Ok(target) => format!("from {}", display_path(&target)),
+                Err(_) => match tool.get_remote_url() {
+                    Some(url) => format!("from {url}"),
+                    None => "built into rtx".into(),
+                },
+            };
+            Ok((tool.clone(), format!("{} {}", tool.name, source)))
+        })?;
+        report.step(out, || {
+            let versions = tool.list_remote_versions(&config)?;
+            match versions.last() {
+                Some(last) => Ok((
+                    (),
+                    format!("{} versions, {} is the last", versions.len(), last),
+                )),
+                None => Err(eyre!("no versions found")),
+            }
+        })?;
+        let tv = report.step(out, || {
+            let tvr = ToolVersionRequest::new(tool.name.clone(), &self.tool_version)?;
+            let tv = tvr.resolve(&config, &tool, Default::default(), true)?;
+            let detail = format!("{} resolved to {}", self.tool_version, tv.version);
+            Ok((tv, detail))
+        })?;
+        report.step(out, || {
+            let mut pr = mpr.add();
+            tool.decorate_progress_bar(&mut pr, Some(&tv));
+            tool.install_version(&config, &tv, &mut pr, true)?;
+            Ok(((), display_path(&tv.install_path())))
+        })?;
+        report.step(out, || {
+            let executables = self.find_executables(&config, &tool, &tv)?;
+            Ok(((), executables.join(", ")))
+        })?;
+        match self.command.is_empty() {
+            true => report.skip(out, "no command given"),
+            false => report.step(out, || {
+                let tool_arg = format!("{}@{}", tool.name, tv.version);
+                let args = ["exec".to_string(), tool_arg, "--".to_string()];
+                let status = cmd(&*env::RTX_EXE, args.iter().chain(&self.command))
+                    .unchecked()
+                    .run()?
+                    .status;
+                let command = self.command.join(" ");
+                match status.success() {
+                    true => Ok(((), command)),
+                    false => Err(eyre!("{} exited with {}", command, status)),
+                }
+            })?,
+        }
+        report.step(out, || {
+            let pr = mpr.add();
+            tool.uninstall_version(&config, &tv, &pr, false)?;
+            Ok(((), tv.to_string()))
+        })
+    }
+
+    /// local plugins are symlinked like `rtx plugins link` does
+    fn build_tool(&self, config: &mut Config) -> Result<Arc<Tool>> {
+        let path = Path::new(&self.plugin);
+        if path.is_dir() {
+            let name = get_name_from_path(path);
+            create_dir_all(&*dirs::PLUGINS)?;
+            make_symlink(path, &dirs::PLUGINS.join(&name))?;
+            return Ok(config.get_or_create_tool(&name));
+        }
+        if self.plugin.contains("://") {
+            let name = get_name_from_url(&self.plugin)?;
+            let mut plugin = ExternalPlugin::new(&name);
+            plugin.repo_url = Some(self.plugin.clone());
+            return Ok(Arc::new(Tool::new(name, Box::new(plugin))));
+        }
+        Ok(config.get_or_create_tool(&self.plugin))
+    }
+
+    /// every bin path must exist and at least one of them must contain an executable
+    fn find_executables(
+        &self,
+        config: &Config,
+        tool: &Tool,
+        tv: &ToolVersion,
+    ) -> Result<Vec<String>> {
+        let bin_paths = tool.list_bin_paths(config, tv)?;
+        let mut executables = vec![];
+        for bin_path in &bin_paths {
+            if !bin_path.is_dir() {
+                return Err(eyre!("{} does not exist", display_path(bin_path)));
+            }
+            executables.extend(
+                file::dir_files(bin_path)?
+                    .into_iter()
+                    .filter(|f| file::is_executable(&bin_path.join(f))),
+            );
+        }
+        if executables.is_empty() {
+            return Err(eyre!(
+                "no executables found in {}",
+                bin_paths.iter().map(|p| display_path(p)).join(", ")
+            ));
+        }
+        Ok(executables)
+    }
+}
+
+/// prints the result of each step as it runs
+#[derive(Default)]
+struct Report {
+    completed: usize,
+}
+
+impl Report {
+    fn step<T>(&mut self, out: &mut Output, f: impl FnOnce() -> Result<(T, String)>) -> Result<T> {
+        let name = STEPS[self.completed];
+        match f() {
+            Ok((result, detail)) => {
+                rtxprintln!(out, "{} {}: {}", style("✓").green(), name, detail);
+                self.completed += 1;
+                Ok(result)
+            }
+            Err(err) => {
+                rtxprintln!(out, "{} {}: {:#}", style("✗").red(), name, err);
+                Err(err)
+            }
+        }
+    }
+
+    fn skip(&mut self, out: &mut Output, reason: &str) {
+        let name = STEPS[self.completed];
+        rtxprintln!(
+            out,
+            "{} {}: {}",
+            style("-").dim(),
+            name,
+            style(reason).dim()
+        );
+        self.completed += 1;
+    }
+}
+
+static AFTER_LONG_HELP: &str = color_print::cstr!(
+    r#"<bold><underline>Examples:</underline></bold>
+  $ <bold>rtx plugins test tiny</bold>
+  $ <bold>rtx plugins test https://github.com/rtx-plugins/rtx-tiny.git</bold>
+
+  # test a local plugin with a specific version and check that it runs
+  $ <bold>rtx plugins test ./rtx-tiny --tool-version 3.1.0 -- rtx-tiny</bold>
+"#
+);
@@ -1,13 +1,19 @@
 use color_eyre::eyre::{eyre, Result};
 use console::style;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
 
 use crate::cli::command::Command;
 use crate::config::Config;
 use crate::output::Output;
-use crate::plugins::PluginName;
+use crate::plugins::{short_sha, PluginName};
+use crate::ui::multi_progress_report::MultiProgressReport;
 
 /// Updates a plugin to the latest version
 ///
+/// Plugins are updated in parallel, set `--jobs=1` or `RTX_JOBS=1` to update one at a time.
+/// The commits each plugin was updated with are shown afterwards.
+///
 /// note: this updates the plugin itself, not the runtime versions
 #[derive(Debug, clap::Args)]
 #[clap(verbatim_doc_comment, alias = "upgrade", after_long_help = AFTER_LONG_HELP)]
@@ -19,6 +25,10 @@ pub struct Update {
     /// Update all plugins
     #[clap(long, short = 'a', conflicts_with = "plugin", hide = true)]
     all: bool,
+
+    /// Only fetch the plugins and show what would be updated
+    #[clap(long, short_alias = 'n')]
+    dry_run: bool,
 }
 
 impl Command for Update {
@@ -44,9 +54,46 @@ impl Command for Update {
                 .collect::<Vec<_>>(),
         };
 
-        for (plugin, ref_) in plugins {
-            rtxprintln!(out, "updating plugin {}", plugin.name);
-            plugin.update(ref_)?;
+        let verb = if self.dry_run {
+            "would update"
+        } else {
+            "updated"
+        };
+        let mpr = MultiProgressReport::new(config.settings.verbose);
+        let updates = ThreadPoolBuilder::new()
+            .num_threads(config.settings.jobs)
+            .build()?
+            .install(|| {
+                plugins
+                    .into_par_iter()
+                    .map(|(plugin, ref_)| {
+                        let mut pr = mpr.add();
+                        let update = plugin.update(&mut pr, ref_, self.dry_run)?;
+                        match &update {
+                            Some(u) if !u.is_up_to_date() => {
+                                pr.finish_with_message(format!("{verb} {}", u.short_revs()))
+                            }
+                            Some(_) => pr.finish_with_message("up to date"),
+                            None => pr.finish(),
+                        }
+                        Ok((plugin.name.clone(), update))
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })?;
+
+        let updates = updates
+            .into_iter()
+            .filter_map(|(name, update)| update.map(|u| (name, u)));
+        for (name, update) in updates {
+            if update.is_up_to_date() {
+                let sha = short_sha(&update.post_rev);
+                rtxprintln!(out, "{} {sha} is up to date", style(&name).cyan());
+                continue;
+            }
+            rtxprintln!(out, "{} {}", style(&name).cyan(), update.short_revs());
+            for subject in update.commits {
+                rtxprintln!(out, "  {}", subject);
+            }
         }
         Ok(())
     }
@@ -57,6 +104,7 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
   $ <bold>rtx plugins update</bold>              # update all plugins
   $ <bold>rtx plugins update node</bold>       # update only node
   $ <bold>rtx plugins update node@beta</bold>  # specify a ref
+  $ <bold>rtx plugins update --dry-run</bold>    # show what would be updated
 "#
 );
 
@@ -75,4 +123,10 @@ mod tests {
         // assert_cli!("p", "update"); tested in e2e
         assert_cli!("plugins", "update", "tiny");
     }
+
+    #[test]
+    fn test_plugin_update_dry_run() {
+        // tiny is not a git repository in the test data, e2e/test_plugins_install covers git
+        assert_cli!("plugins", "update", "--dry-run", "tiny");
+    }
 }
@@ -24,13 +24,17 @@ impl Command for SettingsSet {
             "missing_runtime_behavior" => self.value.into(),
             "always_keep_download" => parse_bool(&self.value)?,
             "legacy_version_file" => parse_bool(&self.value)?,
+            "prerelease" => parse_bool(&self.value)?,
             "plugin_autoupdate_last_check_duration" => parse_i64(&self.value)?,
+            "plugin_script_timeout" => parse_duration(&self.value)?,
+            "plugin_script_retries" => parse_i64(&self.value)?,
             "verbose" => parse_bool(&self.value)?,
             "asdf_compat" => parse_bool(&self.value)?,
             "jobs" => parse_i64(&self.value)?,
             "shorthands_file" => self.value.into(),
             "disable_default_shorthands" => parse_bool(&self.value)?,
             "raw" => parse_bool(&self.value)?,
+            "python_precompiled" => parse_bool(&self.value)?,
             _ => return Err(eyre!("Unknown setting: {}", self.key)),
         };
 
@@ -54,6 +58,17 @@ fn parse_i64(value: &str) -> Result<toml_edit::Value> {
     }
 }
 
+/// minutes like plugin_autoupdate_last_check_duration, or a string like "30s"
+fn parse_duration(value: &str) -> Result<toml_edit::Value> {
+    if let Ok(value) = parse_i64(value) {
+        return Ok(value);
+    }
+    match humantime::parse_duration(value) {
+        Ok(_) => Ok(value.into()),
+        Err(_) => Err(eyre!("{} must be a duration, e.g.: 30s", value)),
+    }
+}
+
 static AFTER_LONG_HELP: &str = color_print::cstr!(
     r#"<bold><underline>Examples:</underline></bold>
   $ <bold>rtx settings set legacy_version_file true</bold>
@@ -79,6 +94,7 @@ pub mod tests {
             "plugin_autoupdate_last_check_duration",
             "1"
         );
+        assert_cli!("settings", "set", "plugin_script_timeout", "30s");
 
         let stdout = assert_cli!("settings");
         assert_snapshot!(stdout);
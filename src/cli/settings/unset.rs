@@ -47,7 +47,12 @@ mod tests {
         missing_runtime_behavior = autoinstall
         always_keep_download = true
         legacy_version_file = true
+        prerelease = false
         plugin_autoupdate_last_check_duration = 20
+        plugin_script_timeout = 0s
+        plugin_script_retries = 0
+        plugin_script_env_allowlist = []
+        plugin_script_env_denylist = []
         trusted_config_paths = []
         verbose = true
         asdf_compat = false
@@ -55,6 +60,7 @@ mod tests {
         disable_default_shorthands = false
         log_level = INFO
         raw = false
+        python_precompiled = false
         "###);
 
         reset_config();
@@ -57,10 +57,10 @@ impl Command for Use {
             .tool
             .into_iter()
             .map(|r| match &r.tvr {
-                Some(_) => r,
-                None => ToolArg::parse(&format!("{}@latest", r.plugin)),
+                Some(_) => Ok(r),
+                None => r.with_version("latest"),
             })
-            .collect();
+            .collect::<Result<Vec<_>>>()?;
         let path = match (self.global, self.path) {
             (true, _) => global_file(),
             (false, Some(p)) => p,
@@ -94,6 +94,9 @@ static AFTER_LONG_HELP: &str = color_print::cstr!(
   # set the current version of node to 20.x in ~/.config/rtx/config.toml
   # will write the precise version (e.g.: 20.0.0)
   $ <bold>rtx use -g --pin node@20</bold>
+
+  # set node to the highest 18.x or 20.x version, the range is written as-is
+  $ <bold>rtx use 'node@^18 || ^20'</bold>
 "#
 );
 
@@ -32,7 +32,7 @@ impl Command for Where {
     fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
         let runtime = match self.tool.tvr {
             None => match self.asdf_version {
-                Some(version) => self.tool.with_version(&version),
+                Some(version) => self.tool.with_version(&version)?,
                 None => {
                     let ts = ToolsetBuilder::new()
                         .with_args(&[self.tool.clone()])
@@ -42,7 +42,8 @@ impl Command for Where {
                         .get(&self.tool.plugin)
                         .and_then(|v| v.requests.first())
                         .map(|(r, _)| r.version());
-                    self.tool.with_version(&v.unwrap_or(String::from("latest")))
+                    self.tool
+                        .with_version(&v.unwrap_or(String::from("latest")))?
                 }
             },
             _ => self.tool,
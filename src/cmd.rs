@@ -1,14 +1,20 @@
 use color_eyre::Result;
+use std::collections::HashSet;
 use std::ffi::{OsStr, OsString};
+use std::io;
 use std::io::{BufRead, BufReader, Write};
-use std::process::{Command, ExitStatus, Stdio};
+use std::os::unix::process::CommandExt;
+use std::process::{Child, Command, ExitStatus, Stdio};
 use std::sync::mpsc::channel;
+use std::sync::Mutex;
 use std::thread;
+use std::time::{Duration, Instant};
 
 use crate::config::Settings;
-use crate::errors::Error::ScriptFailed;
+use crate::errors::Error::{ScriptFailed, ScriptTimeout};
 use crate::ui::progress_report::ProgressReport;
 use duct::{Expression, IntoExecutablePath};
+use once_cell::sync::Lazy;
 
 /// Create a command with any number of of positional arguments, which may be
 /// different types (anything that implements
@@ -87,6 +93,7 @@ pub struct CmdLineRunner<'a> {
     settings: &'a Settings,
     pr: Option<&'a ProgressReport>,
     stdin: Option<String>,
+    timeout: Option<Duration>,
 }
 impl<'a> CmdLineRunner<'a> {
     pub fn new<P: AsRef<OsStr>>(settings: &'a Settings, program: P) -> Self {
@@ -100,6 +107,7 @@ impl<'a> CmdLineRunner<'a> {
             settings,
             pr: None,
             stdin: None,
+            timeout: None,
         }
     }
 
@@ -142,11 +150,20 @@ impl<'a> CmdLineRunner<'a> {
         self
     }
 
+    /// kills the command if it is still running after `timeout`
+    pub fn with_timeout(&mut self, timeout: Option<Duration>) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
+
     pub fn execute(mut self) -> Result<()> {
         debug!("$ {} {}", self.get_program(), self.get_args().join(" "));
         if self.settings.raw {
             return self.execute_raw();
         }
+        if self.timeout.is_some() {
+            self.cmd.process_group(0);
+        }
         let mut cp = self.cmd.spawn()?;
         let stdout = BufReader::new(cp.stdout.take().unwrap());
         let stderr = BufReader::new(cp.stderr.take().unwrap());
@@ -154,11 +171,12 @@ impl<'a> CmdLineRunner<'a> {
         thread::spawn({
             let tx = tx.clone();
             move || {
+                // sends fail if the command timed out and the output is no longer read
                 for line in stdout.lines() {
                     let line = line.unwrap();
-                    tx.send(ChildProcessOutput::Stdout(line)).unwrap();
+                    let _ = tx.send(ChildProcessOutput::Stdout(line));
                 }
-                tx.send(ChildProcessOutput::Done).unwrap();
+                let _ = tx.send(ChildProcessOutput::Done);
             }
         });
         thread::spawn({
@@ -166,9 +184,9 @@ impl<'a> CmdLineRunner<'a> {
             move || {
                 for line in stderr.lines() {
                     let line = line.unwrap();
-                    tx.send(ChildProcessOutput::Stderr(line)).unwrap();
+                    let _ = tx.send(ChildProcessOutput::Stderr(line));
                 }
-                tx.send(ChildProcessOutput::Done).unwrap();
+                let _ = tx.send(ChildProcessOutput::Done);
             }
         });
         if let Some(text) = self.stdin.take() {
@@ -177,11 +195,16 @@ impl<'a> CmdLineRunner<'a> {
                 stdin.write_all(text.as_bytes()).unwrap();
             });
         }
-        thread::spawn(move || {
-            let status = cp.wait().unwrap();
-            tx.send(ChildProcessOutput::ExitStatus(status)).unwrap();
-            tx.send(ChildProcessOutput::Done).unwrap();
-        });
+        let timeout = self.timeout;
+        thread::spawn(
+            move || match wait_timeout(&mut cp, timeout, true).unwrap() {
+                Some(status) => {
+                    tx.send(ChildProcessOutput::ExitStatus(status)).unwrap();
+                    tx.send(ChildProcessOutput::Done).unwrap();
+                }
+                None => tx.send(ChildProcessOutput::Timeout).unwrap(),
+            },
+        );
         let mut combined_output = vec![];
         let mut wait_for_count = 3;
         let mut status = None;
@@ -198,6 +221,11 @@ impl<'a> CmdLineRunner<'a> {
                 ChildProcessOutput::ExitStatus(s) => {
                     status = Some(s);
                 }
+                // the output is not waited on since subprocesses of the command may still be
+                // holding stdout/stderr open
+                ChildProcessOutput::Timeout => {
+                    return self.on_timeout(combined_output.join("\n"));
+                }
                 ChildProcessOutput::Done => {
                     wait_for_count -= 1;
                     if wait_for_count == 0 {
@@ -216,10 +244,12 @@ impl<'a> CmdLineRunner<'a> {
     }
 
     fn execute_raw(mut self) -> Result<()> {
-        let status = self.cmd.spawn()?.wait()?;
-        match status.success() {
-            true => Ok(()),
-            false => self.on_error(String::new(), status),
+        // raw commands stay in rtx's process group so they can read from the terminal
+        let mut cp = self.cmd.spawn()?;
+        match wait_timeout(&mut cp, self.timeout, false)? {
+            Some(status) if status.success() => Ok(()),
+            Some(status) => self.on_error(String::new(), status),
+            None => self.on_timeout(String::new()),
         }
     }
 
@@ -241,6 +271,22 @@ impl<'a> CmdLineRunner<'a> {
     }
 
     fn on_error(&self, output: String, status: ExitStatus) -> Result<()> {
+        self.print_failure(output);
+        let program = self.cmd.get_program().to_string_lossy().to_string();
+        Err(ScriptFailed(program, Some(status)))?
+    }
+
+    fn on_timeout(&self, output: String) -> Result<()> {
+        self.print_failure(output);
+        Err(ScriptTimeout(
+            self.get_program(),
+            None,
+            self.timeout.unwrap(),
+        ))?
+    }
+
+    /// shows the output of a failed command unless it was already shown
+    fn print_failure(&self, output: String) {
         match self.pr {
             Some(pr) => {
                 pr.error();
@@ -252,8 +298,6 @@ impl<'a> CmdLineRunner<'a> {
                 eprintln!("{}", output);
             }
         }
-        let program = self.cmd.get_program().to_string_lossy().to_string();
-        Err(ScriptFailed(program, Some(status)))?
     }
 
     fn get_program(&self) -> String {
@@ -272,16 +316,114 @@ enum ChildProcessOutput {
     Stdout(String),
     Stderr(String),
     ExitStatus(ExitStatus),
+    Timeout,
     Done,
 }
 
+/// waits for the child to exit, returns None if it was killed after running longer than timeout
+/// if the child leads its own process group its subprocesses are killed with it
+fn wait_timeout(
+    cp: &mut Child,
+    timeout: Option<Duration>,
+    process_group: bool,
+) -> io::Result<Option<ExitStatus>> {
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => return cp.wait().map(Some),
+    };
+    let group = process_group.then(|| ProcessGroup::new(cp.id()));
+    let start = Instant::now();
+    loop {
+        if let Some(status) = cp.try_wait()? {
+            return Ok(Some(status));
+        }
+        if start.elapsed() >= timeout {
+            match &group {
+                Some(group) => group.kill()?,
+                None => cp.kill()?,
+            }
+            cp.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+static PROCESS_GROUPS: Lazy<Mutex<HashSet<u32>>> = Lazy::new(Default::default);
+
+/// a command started with `process_group(0)` so a timeout kills the subprocesses it started too
+/// these do not get the terminal's Ctrl-C, so they are tracked for `kill_process_groups`
+pub struct ProcessGroup(u32);
+
+impl ProcessGroup {
+    pub fn new(pid: u32) -> Self {
+        PROCESS_GROUPS.lock().unwrap().insert(pid);
+        Self(pid)
+    }
+
+    pub fn kill(&self) -> io::Result<()> {
+        signal_process_group(self.0, libc::SIGKILL)
+    }
+}
+
+impl Drop for ProcessGroup {
+    fn drop(&mut self) {
+        PROCESS_GROUPS.lock().unwrap().remove(&self.0);
+    }
+}
+
+/// forwards Ctrl-C to the commands running in their own process group
+pub fn kill_process_groups() {
+    if let Ok(groups) = PROCESS_GROUPS.lock() {
+        for pgid in groups.iter() {
+            let _ = signal_process_group(*pgid, libc::SIGINT);
+        }
+    }
+}
+
+fn signal_process_group(pgid: u32, signal: libc::c_int) -> io::Result<()> {
+    match unsafe { libc::kill(-(pgid as libc::pid_t), signal) } {
+        0 => Ok(()),
+        _ => Err(io::Error::last_os_error()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::cmd;
+    use std::fs;
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::cmd::CmdLineRunner;
+    use crate::config::Settings;
+    use crate::dirs;
 
     #[test]
     fn test_cmd() {
         let output = cmd!("echo", "foo", "bar").read().unwrap();
         assert_eq!("foo bar", output);
     }
+
+    #[test]
+    fn test_cmd_line_runner_timeout() {
+        let settings = Settings::default();
+        let mut cmd = CmdLineRunner::new(&settings, "sleep");
+        cmd.arg("10").with_timeout(Some(Duration::from_millis(100)));
+        let err = cmd.execute().unwrap_err();
+        assert_eq!(err.to_string(), "sleep timed out after 100ms");
+    }
+
+    #[test]
+    fn test_cmd_line_runner_timeout_kills_subprocesses() {
+        let settings = Settings::default();
+        let marker = dirs::HOME.join("data/timeout-subprocess");
+        let _ = fs::remove_file(&marker);
+        let mut cmd = CmdLineRunner::new(&settings, "sh");
+        cmd.arg("-c")
+            .arg(format!("(sleep 1; touch {}) & wait", marker.display()))
+            .with_timeout(Some(Duration::from_millis(100)));
+        assert!(cmd.execute().is_err());
+        thread::sleep(Duration::from_millis(1500));
+        assert!(!marker.exists());
+    }
 }
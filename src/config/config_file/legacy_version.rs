@@ -23,7 +23,7 @@ impl LegacyVersionFile {
         let version = plugin.parse_legacy_file(path.as_path(), settings)?;
 
         Ok(Self {
-            toolset: build_toolset(&path, plugin.name.as_str(), version.as_str()),
+            toolset: build_toolset(&path, plugin.name.as_str(), version.as_str())?,
             path,
         })
     }
@@ -54,7 +54,7 @@ impl ConfigFile for LegacyVersionFile {
         unimplemented!()
     }
 
-    fn replace_versions(&mut self, _plugin_name: &PluginName, _versions: &[String]) {
+    fn replace_versions(&mut self, _plugin_name: &PluginName, _versions: &[String]) -> Result<()> {
         unimplemented!()
     }
 
@@ -89,13 +89,13 @@ impl Display for LegacyVersionFile {
     }
 }
 
-fn build_toolset(path: &Path, plugin: &str, version: &str) -> Toolset {
+fn build_toolset(path: &Path, plugin: &str, version: &str) -> Result<Toolset> {
     let mut toolset = Toolset::new(ToolSource::LegacyVersionFile(path.to_path_buf()));
     for version in version.split_whitespace() {
         toolset.add_version(
-            ToolVersionRequest::new(plugin.to_string(), version),
+            ToolVersionRequest::new(plugin.to_string(), version)?,
             Default::default(),
         );
     }
-    toolset
+    Ok(toolset)
 }
@@ -34,13 +34,17 @@ pub trait ConfigFile: Debug + Display + Send + Sync {
     fn get_type(&self) -> ConfigFileType;
     fn get_path(&self) -> &Path;
     fn plugins(&self) -> HashMap<PluginName, String>;
+    /// plugins vendored in the project, the paths are absolute
+    fn plugin_paths(&self) -> HashMap<PluginName, PathBuf> {
+        HashMap::new()
+    }
     fn env(&self) -> HashMap<String, String>;
     fn env_remove(&self) -> Vec<String> {
         vec![]
     }
     fn path_dirs(&self) -> Vec<PathBuf>;
     fn remove_plugin(&mut self, plugin_name: &PluginName);
-    fn replace_versions(&mut self, plugin_name: &PluginName, versions: &[String]);
+    fn replace_versions(&mut self, plugin_name: &PluginName, versions: &[String]) -> Result<()>;
     fn save(&self) -> Result<()>;
     fn dump(&self) -> String;
     fn to_toolset(&self) -> &Toolset;
@@ -93,7 +97,7 @@ impl dyn ConfigFile {
                     }
                 })
                 .collect::<Result<Vec<_>>>()?;
-            self.replace_versions(&plugin, &versions);
+            self.replace_versions(&plugin, &versions)?;
         }
 
         Ok(())
@@ -12,9 +12,9 @@ use toml_edit::{table, value, Array, Document, Item, Value};
 
 use crate::config::config_file::{ConfigFile, ConfigFileType};
 use crate::config::settings::SettingsBuilder;
-use crate::config::{config_file, AliasMap, MissingRuntimeBehavior};
+use crate::config::{config_file, get_global_rtx_toml, AliasMap, MissingRuntimeBehavior};
 use crate::errors::Error::UntrustedConfig;
-use crate::file::create_dir_all;
+use crate::file::{create_dir_all, display_path};
 use crate::plugins::PluginName;
 use crate::tera::{get_tera, BASE_CONTEXT};
 use crate::toolset::{
@@ -36,6 +36,8 @@ pub struct RtxToml {
     alias: AliasMap,
     doc: Document,
     plugins: HashMap<String, String>,
+    plugin_paths: HashMap<String, PathBuf>,
+    hooks: HashMap<String, String>,
     is_trusted: bool,
 }
 
@@ -84,7 +86,8 @@ impl RtxToml {
                 "alias" => self.alias = self.parse_alias(k, v)?,
                 "tools" => self.toolset = self.parse_toolset(k, v)?,
                 "settings" => self.settings = self.parse_settings(k, v)?,
-                "plugins" => self.plugins = self.parse_hashmap(k, v)?,
+                "plugins" => self.parse_plugins(k, v)?,
+                "hooks" => self.hooks = self.parse_hooks(k, v)?,
                 _ => Err(eyre!("unknown key: {}", k))?,
             }
         }
@@ -96,6 +99,11 @@ impl RtxToml {
         self.settings.clone()
     }
 
+    /// user-level hooks, only the ones in the global config are run
+    pub fn hooks(&self) -> &HashMap<String, String> {
+        &self.hooks
+    }
+
     fn parse_env_file(&mut self, k: &str, v: &Item) -> Result<()> {
         self.trust_check()?;
         match v.as_str() {
@@ -227,6 +235,52 @@ impl RtxToml {
         }
     }
 
+    /// plugins are either a git url or a table with the path of a plugin vendored in the project
+    /// e.g.: `mytool = { path = "./tools/rtx-plugins/mytool" }`
+    fn parse_plugins(&mut self, key: &str, v: &Item) -> Result<()> {
+        match v.as_table_like() {
+            Some(table) => {
+                for (name, v) in table.iter() {
+                    let k = format!("{}.{}", key, name);
+                    if let Some(url) = v.as_str() {
+                        let url = self.parse_template(&k, url)?;
+                        self.plugins.insert(name.into(), url);
+                        continue;
+                    }
+                    let path = match v.as_table_like().and_then(|t| t.get("path")) {
+                        Some(path) => match path.as_str() {
+                            Some(path) => self.parse_template(&k, path)?,
+                            None => parse_error!(format!("{}.path", k), path, "string")?,
+                        },
+                        None => parse_error!(k, v, "string or table with path")?,
+                    };
+                    // the scripts of a vendored plugin run without being installed explicitly
+                    self.trust_check()?;
+                    let config_root = self.path.parent().unwrap();
+                    let path = match path.strip_prefix("~/") {
+                        Some(path) => dirs::HOME.join(path),
+                        None => config_root.join(path.strip_prefix("./").unwrap_or(&path)),
+                    };
+                    self.plugin_paths.insert(name.into(), path);
+                }
+                Ok(())
+            }
+            _ => parse_error!(key, v, "table"),
+        }
+    }
+
+    fn parse_hooks(&mut self, key: &str, v: &Item) -> Result<HashMap<String, String>> {
+        let global_config = get_global_rtx_toml();
+        if self.path != global_config {
+            return Err(eyre!(
+                "[{key}] is only supported in the global config: {}",
+                display_path(&global_config)
+            ));
+        }
+        self.trust_check()?;
+        self.parse_hashmap(key, v)
+    }
+
     fn parse_toolset(&mut self, key: &str, v: &Item) -> Result<Toolset> {
         let mut toolset = Toolset::new(self.toolset.source.clone().unwrap());
 
@@ -296,7 +350,7 @@ impl RtxToml {
         v: &Item,
         plugin_name: &PluginName,
     ) -> Result<(ToolVersionRequest, ToolVersionOptions)> {
-        let mut tv = ToolVersionRequest::new(plugin_name.clone(), "system");
+        let mut tv = ToolVersionRequest::System(plugin_name.clone());
         let mut opts = ToolVersionOptions::default();
 
         match v.as_table_like() {
@@ -368,7 +422,7 @@ impl RtxToml {
         match v.as_str() {
             Some(s) => {
                 let s = self.parse_template(key, s)?;
-                Ok(ToolVersionRequest::new(plugin_name.clone(), &s))
+                ToolVersionRequest::new(plugin_name.clone(), &s)
             }
             _ => parse_error!(key, v, "string")?,
         }
@@ -393,10 +447,24 @@ impl RtxToml {
                         "always_keep_download" => {
                             settings.always_keep_download = Some(self.parse_bool(&k, v)?)
                         }
+                        "prerelease" => settings.prerelease = Some(self.parse_bool(&k, v)?),
                         "plugin_autoupdate_last_check_duration" => {
                             settings.plugin_autoupdate_last_check_duration =
                                 Some(self.parse_duration_minutes(&k, v)?)
                         }
+                        "plugin_script_timeout" => {
+                            settings.plugin_script_timeout =
+                                Some(self.parse_duration_minutes(&k, v)?)
+                        }
+                        "plugin_script_retries" => {
+                            settings.plugin_script_retries = Some(self.parse_usize(&k, v)?)
+                        }
+                        "plugin_script_env_allowlist" => {
+                            settings.plugin_script_env_allowlist = self.parse_strings(&k, v)?
+                        }
+                        "plugin_script_env_denylist" => {
+                            settings.plugin_script_env_denylist = self.parse_strings(&k, v)?
+                        }
                         "trusted_config_paths" => {
                             settings.trusted_config_paths = self.parse_paths(&k, v)?;
                         }
@@ -411,6 +479,9 @@ impl RtxToml {
                         }
                         "log_level" => settings.log_level = Some(self.parse_log_level(&k, v)?),
                         "raw" => settings.raw = Some(self.parse_bool(&k, v)?),
+                        "python_precompiled" => {
+                            settings.python_precompiled = Some(self.parse_bool(&k, v)?)
+                        }
                         _ => Err(eyre!("Unknown config setting: {}", k))?,
                     };
                 }
@@ -506,6 +577,23 @@ impl RtxToml {
         }
     }
 
+    fn parse_strings(&mut self, k: &str, v: &Item) -> Result<Vec<String>> {
+        match v.as_value().map(|v| v.as_array()) {
+            Some(Some(v)) => {
+                let mut strings = vec![];
+                for (i, v) in v.iter().enumerate() {
+                    let k = format!("{}.{}", k, i);
+                    match v.as_str() {
+                        Some(v) => strings.push(v.to_string()),
+                        _ => parse_error!(k, v, "string")?,
+                    }
+                }
+                Ok(strings)
+            }
+            _ => parse_error!(k, v, "array of strings")?,
+        }
+    }
+
     fn parse_string(&mut self, k: &str, v: &Item) -> Result<String> {
         match v.as_value().map(|v| v.as_str()) {
             Some(Some(v)) => {
@@ -637,6 +725,10 @@ impl ConfigFile for RtxToml {
         self.plugins.clone()
     }
 
+    fn plugin_paths(&self) -> HashMap<PluginName, PathBuf> {
+        self.plugin_paths.clone()
+    }
+
     fn env(&self) -> HashMap<String, String> {
         self.env.clone()
     }
@@ -661,17 +753,15 @@ impl ConfigFile for RtxToml {
         }
     }
 
-    fn replace_versions(&mut self, plugin_name: &PluginName, versions: &[String]) {
+    fn replace_versions(&mut self, plugin_name: &PluginName, versions: &[String]) -> Result<()> {
         if let Some(plugin) = self.toolset.versions.get_mut(plugin_name) {
             plugin.requests = versions
                 .iter()
                 .map(|s| {
-                    (
-                        ToolVersionRequest::new(plugin_name.clone(), s),
-                        Default::default(),
-                    )
+                    let tvr = ToolVersionRequest::new(plugin_name.clone(), s)?;
+                    Ok((tvr, Default::default()))
                 })
-                .collect();
+                .collect::<Result<_>>()?;
         }
         let tools = self
             .doc
@@ -689,6 +779,7 @@ impl ConfigFile for RtxToml {
             }
             tools.insert(plugin_name, Item::Value(Value::Array(arr)));
         }
+        Ok(())
     }
 
     fn save(&self) -> Result<()> {
@@ -784,6 +875,58 @@ mod tests {
         assert_display_snapshot!(cf);
     }
 
+    #[test]
+    fn test_hooks() {
+        let mut cf = RtxToml::init(&get_global_rtx_toml(), true);
+        cf.parse(&formatdoc! {r#"
+        [hooks]
+        post_install_node = "npm i -g yarn"
+        "#})
+            .unwrap();
+
+        assert_debug_snapshot!(cf.hooks(), @r###"
+        {
+            "post_install_node": "npm i -g yarn",
+        }
+        "###);
+    }
+
+    #[test]
+    fn test_hooks_local_config() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        let err = cf.parse("[hooks]\npost_install_node = 'npm i -g yarn'\n");
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "[hooks] is only supported in the global config: ~/config/config.toml"
+        );
+    }
+
+    #[test]
+    fn test_plugin_paths() {
+        let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
+        cf.parse(&formatdoc! {r#"
+        [plugins]
+        node = "https://github.com/rtx-plugins/rtx-nodejs"
+        mytool = {{ path = "./tools/rtx-plugins/mytool" }}
+        "#})
+            .unwrap();
+
+        assert_debug_snapshot!(cf.plugins(), @r###"
+        {
+            "node": "https://github.com/rtx-plugins/rtx-nodejs",
+        }
+        "###);
+        assert_debug_snapshot!(cf.plugin_paths(), @r###"
+        {
+            "mytool": "/tmp/tools/rtx-plugins/mytool",
+        }
+        "###);
+        let err = cf.parse("plugins.mytool = { url = 'x' }").unwrap_err();
+        assert!(err.to_string().starts_with(
+            r#"expected value of "plugins.mytool" to be a string or table with path"#
+        ));
+    }
+
     #[test]
     fn test_set_alias() {
         let mut cf = RtxToml::init(PathBuf::from("/tmp/.rtx.toml").as_path(), true);
@@ -832,7 +975,8 @@ mod tests {
         cf.replace_versions(
             &PluginName::from("node"),
             &["16.0.1".into(), "18.0.1".into()],
-        );
+        )
+        .unwrap();
 
         assert_debug_snapshot!(cf.toolset);
         assert_display_snapshot!(cf);
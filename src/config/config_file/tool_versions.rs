@@ -74,7 +74,7 @@ impl ToolVersions {
         }
 
         cf.plugins = Self::parse_plugins(&s)?;
-        cf.populate_toolset();
+        cf.populate_toolset()?;
         Ok(cf)
     }
 
@@ -119,13 +119,14 @@ impl ToolVersions {
             .push(version.to_string());
     }
 
-    fn populate_toolset(&mut self) {
+    fn populate_toolset(&mut self) -> Result<()> {
         for (plugin, tvp) in &self.plugins {
             for version in &tvp.versions {
-                let tvr = ToolVersionRequest::new(plugin.clone(), version);
+                let tvr = ToolVersionRequest::new(plugin.clone(), version)?;
                 self.toolset.add_version(tvr, Default::default())
             }
         }
+        Ok(())
     }
 }
 
@@ -170,11 +171,12 @@ impl ConfigFile for ToolVersions {
         self.plugins.remove(plugin);
     }
 
-    fn replace_versions(&mut self, plugin_name: &PluginName, versions: &[String]) {
+    fn replace_versions(&mut self, plugin_name: &PluginName, versions: &[String]) -> Result<()> {
         self.get_or_create_plugin(plugin_name).versions.clear();
         for version in versions {
             self.add_version(plugin_name, version);
         }
+        Ok(())
     }
 
     fn save(&self) -> Result<()> {
@@ -0,0 +1,333 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use itertools::Itertools;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::config::{is_global_config, Config};
+use crate::env::RTX_LOCKFILE;
+use crate::file::display_path;
+use crate::git::Git;
+use crate::plugins::{PluginName, PluginType};
+use crate::tool::Tool;
+use crate::toolset::{ToolSource, ToolVersion, Toolset};
+
+/// `.rtx.lock` pins the plugin commits and resolved tool versions of the config file next to it
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Lockfile {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub plugins: BTreeMap<PluginName, LockedPlugin>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tools: BTreeMap<PluginName, Vec<LockedTool>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockedPlugin {
+    pub url: String,
+    pub sha: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockedTool {
+    pub request: String,
+    pub version: String,
+}
+
+impl Lockfile {
+    pub fn path(config_path: &Path) -> PathBuf {
+        config_path.with_file_name(".rtx.lock")
+    }
+
+    pub fn read(path: &Path) -> Result<Self> {
+        let body = fs::read_to_string(path)
+            .wrap_err_with(|| format!("no lockfile found at {}", display_path(path)))?;
+        toml::from_str(&body).wrap_err_with(|| format!("failed to parse {}", display_path(path)))
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        trace!("writing lockfile: {}", display_path(path));
+        fs::write(path, toml::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// records the resolved versions and plugin commits of the toolset in the `.rtx.lock` next to
+/// each config file, lockfiles are only created if RTX_LOCKFILE=1 but are always kept up to date
+pub fn update_lockfiles(config: &Config, ts: &Toolset) -> Result<()> {
+    let mut lockfiles: BTreeMap<PathBuf, Lockfile> = BTreeMap::new();
+    for (plugin_name, tvl) in &ts.versions {
+        let path = match source_path(&tvl.source) {
+            Some(path) => path,
+            None => continue,
+        };
+        let lock_path = Lockfile::path(path);
+        if !*RTX_LOCKFILE && !lock_path.exists() {
+            continue;
+        }
+        if !lockfiles.contains_key(&lock_path) {
+            let lockfile = match lock_path.exists() {
+                true => Lockfile::read(&lock_path)?,
+                false => Lockfile::default(),
+            };
+            lockfiles.insert(lock_path.clone(), lockfile);
+        }
+        let lockfile = lockfiles.get_mut(&lock_path).unwrap();
+        if let Some(plugin) = config.tools.get(plugin_name).and_then(|t| locked_plugin(t)) {
+            lockfile.plugins.insert(plugin_name.clone(), plugin);
+        }
+        let tools = tvl
+            .versions
+            .iter()
+            .map(|tv| LockedTool {
+                request: tv.request.version(),
+                version: tv.version.clone(),
+            })
+            .collect();
+        lockfile.tools.insert(plugin_name.clone(), tools);
+    }
+    for (path, lockfile) in lockfiles {
+        lockfile.write(&path)?;
+    }
+    Ok(())
+}
+
+/// replaces the requested versions with the ones in the `.rtx.lock` files and checks out the
+/// locked plugin commits, fails if a lockfile is missing or does not match the config
+/// tools in the global config are only locked if it has a lockfile
+pub fn apply_lockfiles(config: &mut Config, ts: &mut Toolset) -> Result<()> {
+    let mut lockfiles: BTreeMap<PathBuf, Lockfile> = BTreeMap::new();
+    for (plugin_name, tvl) in ts.versions.iter_mut() {
+        let path = match source_path(&tvl.source) {
+            Some(path) => path,
+            None => continue,
+        };
+        let lock_path = Lockfile::path(path);
+        if is_global_config(path) && !lock_path.exists() {
+            tvl.resolve(config, false);
+            continue;
+        }
+        if !lockfiles.contains_key(&lock_path) {
+            lockfiles.insert(lock_path.clone(), Lockfile::read(&lock_path)?);
+        }
+        let lockfile = &lockfiles[&lock_path];
+        let missing = || eyre!("{} is missing {}", display_path(&lock_path), plugin_name);
+        let locked = lockfile.tools.get(plugin_name).ok_or_else(missing)?;
+        let requests = tvl.requests.iter().map(|(tvr, _)| tvr.version());
+        if !requests.eq(locked.iter().map(|t| t.request.clone())) {
+            return Err(eyre!(
+                "{} is out of date for {}, run `rtx install` to update it",
+                display_path(&lock_path),
+                plugin_name
+            ));
+        }
+        let tool = config.get_or_create_tool(plugin_name);
+        if matches!(tool.plugin.get_type(), PluginType::External) && !tool.is_vendored() {
+            let plugin = lockfile.plugins.get(plugin_name).ok_or_else(missing)?;
+            checkout_plugin(&tool, plugin)?;
+        }
+        tvl.versions = tvl
+            .requests
+            .iter()
+            .zip_eq(locked)
+            .map(|((tvr, opts), lt)| {
+                ToolVersion::new(&tool, tvr.clone(), opts.clone(), lt.version.clone())
+            })
+            .collect();
+    }
+    Ok(())
+}
+
+/// plugins in the `.rtx.lock` next to any of the config files stay at the locked commit
+pub fn is_locked(config: &Config, plugin_name: &str) -> bool {
+    config.config_files.keys().any(|path| {
+        let lock_path = Lockfile::path(path);
+        lock_path.exists()
+            && Lockfile::read(&lock_path).map_or(false, |l| l.plugins.contains_key(plugin_name))
+    })
+}
+
+/// tools from arguments or RTX_*_VERSION env vars are not locked
+fn source_path(source: &ToolSource) -> Option<&Path> {
+    match source {
+        ToolSource::RtxToml(path)
+        | ToolSource::ToolVersions(path)
+        | ToolSource::LegacyVersionFile(path) => Some(path),
+        ToolSource::Argument | ToolSource::Environment(..) => None,
+    }
+}
+
+fn locked_plugin(tool: &Tool) -> Option<LockedPlugin> {
+    let git = Git::new(tool.plugin_path.clone());
+    if !matches!(tool.plugin.get_type(), PluginType::External) || !git.is_repo() {
+        return None;
+    }
+    Some(LockedPlugin {
+        url: git.get_remote_url()?,
+        sha: git.current_sha().ok()?,
+    })
+}
+
+fn checkout_plugin(tool: &Tool, locked: &LockedPlugin) -> Result<()> {
+    let git = Git::new(tool.plugin_path.clone());
+    if !tool.is_installed() {
+        git.clone(&locked.url)?;
+    }
+    if git.current_sha()? != locked.sha {
+        git.checkout_sha(&locked.sha)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::file::{create_dir_all, remove_all};
+    use crate::toolset::ToolVersionRequest;
+    use crate::ui::multi_progress_report::MultiProgressReport;
+    use crate::{cmd, dirs};
+
+    #[test]
+    fn test_lockfile_roundtrip() {
+        let lockfile = Lockfile {
+            plugins: BTreeMap::from([(
+                "tiny".to_string(),
+                LockedPlugin {
+                    url: "https://github.com/rtx-plugins/rtx-tiny".into(),
+                    sha: "abc123".into(),
+                },
+            )]),
+            tools: BTreeMap::from([(
+                "tiny".to_string(),
+                vec![LockedTool {
+                    request: "3".into(),
+                    version: "3.1.0".into(),
+                }],
+            )]),
+        };
+        let body = toml::to_string_pretty(&lockfile).unwrap();
+        assert_eq!(
+            body,
+            indoc! {r#"
+                [plugins.tiny]
+                url = "https://github.com/rtx-plugins/rtx-tiny"
+                sha = "abc123"
+
+                [[tools.tiny]]
+                request = "3"
+                version = "3.1.0"
+            "#}
+        );
+        assert_eq!(toml::from_str::<Lockfile>(&body).unwrap(), lockfile);
+    }
+
+    #[test]
+    fn test_lockfile_path() {
+        assert_eq!(
+            Lockfile::path(Path::new("/foo/.rtx.toml")),
+            PathBuf::from("/foo/.rtx.lock")
+        );
+    }
+
+    #[test]
+    fn test_install_locked() {
+        let mut config = Config::load().unwrap();
+        let project = dirs::HOME.join("data/lockfile-project");
+        let tool = Arc::new(Tool::vendored(
+            "vendored".into(),
+            dirs::HOME.join("fixtures/vendored-plugin"),
+        ));
+        let _ = remove_all(&project);
+        let _ = remove_all(&tool.installs_path);
+        create_dir_all(&project).unwrap();
+        config.tools.insert(tool.name.clone(), tool.clone());
+        // the lockfile wins over the version "latest" resolves to
+        fs::write(
+            project.join(".rtx.lock"),
+            indoc! {r#"
+                [[tools.vendored]]
+                request = "latest"
+                version = "0.9.0"
+            "#},
+        )
+        .unwrap();
+        let mut ts = Toolset::new(ToolSource::RtxToml(project.join(".rtx.toml")));
+        ts.add_version(
+            ToolVersionRequest::Version(tool.name.clone(), "latest".into()),
+            Default::default(),
+        );
+        apply_lockfiles(&mut config, &mut ts).unwrap();
+        let mpr = MultiProgressReport::new(config.settings.verbose);
+        ts.install_missing(&mut config, mpr).unwrap();
+        assert!(tool.installs_path.join("0.9.0/bin").is_dir());
+        assert!(!tool.installs_path.join("1.0.0").exists());
+        remove_all(&project).unwrap();
+        remove_all(&tool.installs_path).unwrap();
+    }
+
+    #[test]
+    fn test_install_locked_stale() {
+        let mut config = Config::load().unwrap();
+        let project = dirs::HOME.join("data/lockfile-stale-project");
+        let _ = remove_all(&project);
+        create_dir_all(&project).unwrap();
+        fs::write(
+            project.join(".rtx.lock"),
+            indoc! {r#"
+                [[tools.tiny]]
+                request = "2"
+                version = "2.1.0"
+            "#},
+        )
+        .unwrap();
+        let mut ts = Toolset::new(ToolSource::ToolVersions(project.join(".tool-versions")));
+        ts.add_version(
+            ToolVersionRequest::Version("tiny".into(), "3".into()),
+            Default::default(),
+        );
+        let err = apply_lockfiles(&mut config, &mut ts).unwrap_err();
+        remove_all(&project).unwrap();
+        assert_eq!(
+            err.to_string(),
+            "~/data/lockfile-stale-project/.rtx.lock is out of date for tiny, run `rtx install` to update it"
+        );
+    }
+
+    #[test]
+    fn test_checkout_plugin() {
+        let remote = dirs::HOME.join("data/lockfile-remote");
+        let plugin_path = dirs::HOME.join("data/lockfile-plugin");
+        let _ = remove_all(&remote);
+        let _ = remove_all(&plugin_path);
+        create_dir_all(&remote).unwrap();
+        let git = |args: &[&str]| {
+            let mut cmd_args = vec!["-C", remote.to_str().unwrap(), "-c", "user.name=rtx"];
+            cmd_args.extend(["-c", "user.email=rtx@example.com"]);
+            cmd_args.extend(args);
+            cmd::cmd("git", cmd_args).read().unwrap()
+        };
+        git(&["init", "-q"]);
+        git(&["commit", "-q", "--allow-empty", "-m", "locked"]);
+        let sha = git(&["rev-parse", "HEAD"]);
+        git(&["commit", "-q", "--allow-empty", "-m", "newer"]);
+
+        let tool = Tool::vendored("locked".into(), plugin_path.clone());
+        let locked = LockedPlugin {
+            url: format!("file://{}", remote.display()),
+            sha: sha.clone(),
+        };
+        let result = checkout_plugin(&tool, &locked);
+        let plugin_git = Git::new(plugin_path.clone());
+        let current = (plugin_git.current_sha().ok(), plugin_git.current_branch());
+        remove_all(&remote).unwrap();
+        remove_all(&plugin_path).unwrap();
+        result.unwrap();
+        assert_eq!(current, (Some(sha), None));
+    }
+}
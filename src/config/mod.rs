@@ -1,6 +1,6 @@
 use std::collections::{BTreeMap, HashMap};
 use std::fmt::{Display, Formatter};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::thread;
 
@@ -18,12 +18,13 @@ use crate::config::config_file::{ConfigFile, ConfigFileType};
 use crate::config::tracking::Tracker;
 use crate::env::CI;
 use crate::plugins::core::{CORE_PLUGINS, EXPERIMENTAL_CORE_PLUGINS};
-use crate::plugins::{ExternalPlugin, Plugin, PluginName, PluginType};
+use crate::plugins::{backends, ExternalPlugin, Plugin, PluginName, PluginType};
 use crate::shorthands::{get_shorthands, Shorthands};
 use crate::tool::Tool;
 use crate::{cli, dirs, duration, env, file, hook_env};
 
 pub mod config_file;
+pub mod lockfile;
 mod settings;
 mod tracking;
 
@@ -54,7 +55,7 @@ impl Config {
         let mut settings_b = global_config.settings();
         let settings = settings_b.build();
         let config_filenames = load_config_filenames(&settings, &BTreeMap::new());
-        let tools = load_tools(&settings)?;
+        let mut tools = load_tools(&settings)?;
         let config_files = load_all_config_files(
             &settings_b.build(),
             &config_filenames,
@@ -62,6 +63,7 @@ impl Config {
             &BTreeMap::new(),
             ConfigMap::new(),
         )?;
+        tools.extend(load_vendored_tools(&config_files));
         for cf in config_files.values() {
             settings_b.merge(cf.settings());
         }
@@ -143,8 +145,8 @@ impl Config {
             }
         }
         if let Some(plugin) = self.tools.get(plugin_name) {
-            if let Some(alias) = plugin.get_aliases(&self.settings)?.get(v) {
-                return Ok(alias.clone());
+            if let Some(alias) = plugin.get_alias(&self.settings, v)? {
+                return Ok(alias);
             }
         }
         Ok(v.to_string())
@@ -162,8 +164,9 @@ impl Config {
         self.tools
             .entry(plugin_name.clone())
             .or_insert_with(|| {
-                let plugin = ExternalPlugin::new(plugin_name);
-                build_tool(plugin_name.clone(), Box::new(plugin))
+                let plugin = backends::build(plugin_name)
+                    .unwrap_or_else(|| Box::new(ExternalPlugin::new(plugin_name)));
+                build_tool(plugin_name.clone(), plugin)
             })
             .clone()
     }
@@ -245,7 +248,8 @@ impl Config {
             if matches!(tool.plugin.get_type(), PluginType::External) {
                 return false;
             }
-            plugin_name == "nodejs" || plugin_name == "golang"
+            // backend tools like "github:BurntSushi/ripgrep" are not plugins
+            plugin_name == "nodejs" || plugin_name == "golang" || plugin_name.contains(':')
         })
     }
 }
@@ -306,6 +310,17 @@ fn load_tools(settings: &Settings) -> Result<ToolMap> {
     Ok(tools)
 }
 
+/// plugins vendored with `[plugins] name = { path = "..." }`, the config file closest to the
+/// current directory takes precedence
+fn load_vendored_tools(config_files: &ConfigMap) -> ToolMap {
+    config_files
+        .values()
+        .rev()
+        .flat_map(|cf| cf.plugin_paths())
+        .map(|(name, path)| (name.clone(), Arc::new(Tool::vendored(name, path))))
+        .collect()
+}
+
 fn build_tool(name: PluginName, plugin: Box<dyn Plugin>) -> Arc<Tool> {
     Arc::new(Tool::new(name, plugin))
 }
@@ -369,6 +384,12 @@ fn load_config_filenames(
     config_files.into_iter().unique().collect()
 }
 
+/// ~/.tool-versions and ~/.config/rtx/config.toml
+pub fn is_global_config(path: &Path) -> bool {
+    path == get_global_rtx_toml()
+        || path == dirs::HOME.join(env::RTX_DEFAULT_TOOL_VERSIONS_FILENAME.as_str())
+}
+
 fn get_global_rtx_toml() -> PathBuf {
     match env::RTX_CONFIG_FILE.clone() {
         Some(global) => global,
@@ -505,4 +526,19 @@ mod tests {
         let config = Config::load().unwrap();
         assert_display_snapshot!(config);
     }
+
+    #[test]
+    fn test_load_vendored_plugin() {
+        let path = dirs::HOME.join("fixtures/vendored.rtx.toml");
+        let cf: Box<dyn ConfigFile> = Box::new(RtxToml::from_file(&path, true).unwrap());
+        let tools = load_vendored_tools(&ConfigMap::from([(path, cf)]));
+        let tool = tools.get("vendored").unwrap();
+        assert!(tool.is_vendored());
+        assert_eq!(
+            tool.plugin_path,
+            dirs::HOME.join("fixtures/vendored-plugin")
+        );
+        assert!(tool.dirname.starts_with("vendored-"));
+        assert_eq!(tool.installs_path, dirs::INSTALLS.join(&tool.dirname));
+    }
 }
@@ -14,7 +14,12 @@ pub struct Settings {
     pub missing_runtime_behavior: MissingRuntimeBehavior,
     pub always_keep_download: bool,
     pub legacy_version_file: bool,
+    pub prerelease: bool,
     pub plugin_autoupdate_last_check_duration: Duration,
+    pub plugin_script_timeout: Duration,
+    pub plugin_script_retries: usize,
+    pub plugin_script_env_allowlist: Vec<String>,
+    pub plugin_script_env_denylist: Vec<String>,
     pub trusted_config_paths: Vec<PathBuf>,
     pub verbose: bool,
     pub asdf_compat: bool,
@@ -23,6 +28,7 @@ pub struct Settings {
     pub disable_default_shorthands: bool,
     pub log_level: LevelFilter,
     pub raw: bool,
+    pub python_precompiled: bool,
 }
 
 impl Default for Settings {
@@ -32,7 +38,12 @@ impl Default for Settings {
             missing_runtime_behavior: MissingRuntimeBehavior::Warn,
             always_keep_download: false,
             legacy_version_file: true,
+            prerelease: *RTX_PRERELEASE,
             plugin_autoupdate_last_check_duration: Duration::from_secs(60 * 60 * 24 * 7),
+            plugin_script_timeout: Duration::ZERO,
+            plugin_script_retries: 0,
+            plugin_script_env_allowlist: vec![],
+            plugin_script_env_denylist: vec![],
             trusted_config_paths: RTX_TRUSTED_CONFIG_PATHS.clone(),
             verbose: *RTX_VERBOSE || !console::user_attended_stderr(),
             asdf_compat: *RTX_ASDF_COMPAT,
@@ -41,6 +52,7 @@ impl Default for Settings {
             disable_default_shorthands: *RTX_DISABLE_DEFAULT_SHORTHANDS,
             log_level: *RTX_LOG_LEVEL,
             raw: *RTX_RAW,
+            python_precompiled: *RTX_PYTHON_PRECOMPILED,
         }
     }
 }
@@ -61,10 +73,27 @@ impl Settings {
             "legacy_version_file".to_string(),
             self.legacy_version_file.to_string(),
         );
+        map.insert("prerelease".to_string(), self.prerelease.to_string());
         map.insert(
             "plugin_autoupdate_last_check_duration".to_string(),
             (self.plugin_autoupdate_last_check_duration.as_secs() / 60).to_string(),
         );
+        map.insert(
+            "plugin_script_timeout".to_string(),
+            humantime::format_duration(self.plugin_script_timeout).to_string(),
+        );
+        map.insert(
+            "plugin_script_retries".to_string(),
+            self.plugin_script_retries.to_string(),
+        );
+        map.insert(
+            "plugin_script_env_allowlist".to_string(),
+            format!("{:?}", self.plugin_script_env_allowlist),
+        );
+        map.insert(
+            "plugin_script_env_denylist".to_string(),
+            format!("{:?}", self.plugin_script_env_denylist),
+        );
         map.insert(
             "trusted_config_paths".to_string(),
             format!("{:?}", self.trusted_config_paths),
@@ -84,6 +113,10 @@ impl Settings {
         );
         map.insert("log_level".into(), self.log_level.to_string());
         map.insert("raw".into(), self.raw.to_string());
+        map.insert(
+            "python_precompiled".into(),
+            self.python_precompiled.to_string(),
+        );
         map
     }
 }
@@ -94,7 +127,12 @@ pub struct SettingsBuilder {
     pub missing_runtime_behavior: Option<MissingRuntimeBehavior>,
     pub always_keep_download: Option<bool>,
     pub legacy_version_file: Option<bool>,
+    pub prerelease: Option<bool>,
     pub plugin_autoupdate_last_check_duration: Option<Duration>,
+    pub plugin_script_timeout: Option<Duration>,
+    pub plugin_script_retries: Option<usize>,
+    pub plugin_script_env_allowlist: Vec<String>,
+    pub plugin_script_env_denylist: Vec<String>,
     pub trusted_config_paths: Vec<PathBuf>,
     pub verbose: Option<bool>,
     pub asdf_compat: Option<bool>,
@@ -103,6 +141,7 @@ pub struct SettingsBuilder {
     pub disable_default_shorthands: Option<bool>,
     pub log_level: Option<LevelFilter>,
     pub raw: Option<bool>,
+    pub python_precompiled: Option<bool>,
 }
 
 impl SettingsBuilder {
@@ -125,10 +164,23 @@ impl SettingsBuilder {
         if other.legacy_version_file.is_some() {
             self.legacy_version_file = other.legacy_version_file;
         }
+        if other.prerelease.is_some() {
+            self.prerelease = other.prerelease;
+        }
         if other.plugin_autoupdate_last_check_duration.is_some() {
             self.plugin_autoupdate_last_check_duration =
                 other.plugin_autoupdate_last_check_duration;
         }
+        if other.plugin_script_timeout.is_some() {
+            self.plugin_script_timeout = other.plugin_script_timeout;
+        }
+        if other.plugin_script_retries.is_some() {
+            self.plugin_script_retries = other.plugin_script_retries;
+        }
+        self.plugin_script_env_allowlist
+            .extend(other.plugin_script_env_allowlist);
+        self.plugin_script_env_denylist
+            .extend(other.plugin_script_env_denylist);
         self.trusted_config_paths.extend(other.trusted_config_paths);
         if other.verbose.is_some() {
             self.verbose = other.verbose;
@@ -151,6 +203,9 @@ impl SettingsBuilder {
         if other.raw.is_some() {
             self.raw = other.raw;
         }
+        if other.python_precompiled.is_some() {
+            self.python_precompiled = other.python_precompiled;
+        }
         self
     }
 
@@ -177,9 +232,22 @@ impl SettingsBuilder {
         settings.legacy_version_file = self
             .legacy_version_file
             .unwrap_or(settings.legacy_version_file);
+        settings.prerelease = self.prerelease.unwrap_or(settings.prerelease);
         settings.plugin_autoupdate_last_check_duration = self
             .plugin_autoupdate_last_check_duration
             .unwrap_or(settings.plugin_autoupdate_last_check_duration);
+        settings.plugin_script_timeout = self
+            .plugin_script_timeout
+            .unwrap_or(settings.plugin_script_timeout);
+        settings.plugin_script_retries = self
+            .plugin_script_retries
+            .unwrap_or(settings.plugin_script_retries);
+        settings
+            .plugin_script_env_allowlist
+            .extend(self.plugin_script_env_allowlist.clone());
+        settings
+            .plugin_script_env_denylist
+            .extend(self.plugin_script_env_denylist.clone());
         settings
             .trusted_config_paths
             .extend(self.trusted_config_paths.clone());
@@ -192,6 +260,9 @@ impl SettingsBuilder {
             .unwrap_or(settings.disable_default_shorthands);
         settings.log_level = self.log_level.unwrap_or(settings.log_level);
         settings.raw = self.raw.unwrap_or(settings.raw);
+        settings.python_precompiled = self
+            .python_precompiled
+            .unwrap_or(settings.python_precompiled);
 
         if settings.raw {
             settings.verbose = true;
@@ -38,6 +38,7 @@ pub static RTX_DEFAULT_CONFIG_FILENAME: Lazy<String> =
 pub static RTX_ENV: Lazy<Option<String>> = Lazy::new(|| var("RTX_ENV").ok());
 pub static RTX_CONFIG_FILE: Lazy<Option<PathBuf>> = Lazy::new(|| var_path("RTX_CONFIG_FILE"));
 pub static RTX_USE_TOML: Lazy<bool> = Lazy::new(|| var_is_true("RTX_USE_TOML"));
+pub static RTX_LOCKFILE: Lazy<bool> = Lazy::new(|| var_is_true("RTX_LOCKFILE"));
 pub static RTX_EXE: Lazy<PathBuf> = Lazy::new(|| current_exe().unwrap_or_else(|_| "rtx".into()));
 pub static RTX_LOG_LEVEL: Lazy<LevelFilter> = Lazy::new(log_level);
 pub static RTX_LOG_FILE_LEVEL: Lazy<LevelFilter> = Lazy::new(log_file_level);
@@ -72,6 +73,7 @@ pub static PATH: Lazy<Vec<PathBuf>> = Lazy::new(|| match PRISTINE_ENV.get("PATH"
 pub static DIRENV_DIFF: Lazy<Option<String>> = Lazy::new(|| var("DIRENV_DIFF").ok());
 pub static RTX_CONFIRM: Lazy<Confirm> = Lazy::new(|| var_confirm("RTX_CONFIRM"));
 pub static RTX_EXPERIMENTAL: Lazy<bool> = Lazy::new(|| var_is_true("RTX_EXPERIMENTAL"));
+pub static RTX_PRERELEASE: Lazy<bool> = Lazy::new(|| var_is_true("RTX_PRERELEASE"));
 pub static RTX_HIDE_UPDATE_WARNING: Lazy<bool> =
     Lazy::new(|| var_is_true("RTX_HIDE_UPDATE_WARNING"));
 pub static RTX_ASDF_COMPAT: Lazy<bool> = Lazy::new(|| var_is_true("RTX_ASDF_COMPAT"));
@@ -79,13 +81,19 @@ pub static RTX_SHORTHANDS_FILE: Lazy<Option<PathBuf>> =
     Lazy::new(|| var_path("RTX_SHORTHANDS_FILE"));
 pub static RTX_DISABLE_DEFAULT_SHORTHANDS: Lazy<bool> =
     Lazy::new(|| var_is_true("RTX_DISABLE_DEFAULT_SHORTHANDS"));
+pub static RTX_REGISTRY_URL: Lazy<String> = Lazy::new(|| {
+    var("RTX_REGISTRY_URL").unwrap_or_else(|_| {
+        "https://raw.githubusercontent.com/jdxcode/rtx/main/registry.toml".into()
+    })
+});
 pub static RTX_RAW: Lazy<bool> = Lazy::new(|| var_is_true("RTX_RAW"));
 pub static RTX_TRUSTED_CONFIG_PATHS: Lazy<Vec<PathBuf>> = Lazy::new(|| {
     var("RTX_TRUSTED_CONFIG_PATHS")
         .map(|v| split_paths(&v).collect())
         .unwrap_or_default()
 });
-#[allow(unused)]
+pub static RTX_GITHUB_API_URL: Lazy<String> =
+    Lazy::new(|| var("RTX_GITHUB_API_URL").unwrap_or_else(|_| "https://api.github.com".into()));
 pub static GITHUB_API_TOKEN: Lazy<Option<String>> = Lazy::new(|| var("GITHUB_API_TOKEN").ok());
 
 // python
@@ -97,6 +105,14 @@ pub static RTX_PYTHON_DEFAULT_PACKAGES_FILE: Lazy<PathBuf> = Lazy::new(|| {
     var_path("RTX_PYTHON_DEFAULT_PACKAGES_FILE")
         .unwrap_or_else(|| HOME.join(".default-python-packages"))
 });
+pub static RTX_PYTHON_PRECOMPILED: Lazy<bool> = Lazy::new(|| var_is_true("RTX_PYTHON_PRECOMPILED"));
+pub static RTX_PYTHON_PRECOMPILED_MANIFEST: Lazy<String> = Lazy::new(|| {
+    var("RTX_PYTHON_PRECOMPILED_MANIFEST").unwrap_or_else(|_| {
+        "https://api.github.com/repos/indygreg/python-build-standalone/releases/latest".into()
+    })
+});
+pub static RTX_PYTHON_PRECOMPILED_TARGET: Lazy<Option<String>> =
+    Lazy::new(|| var("RTX_PYTHON_PRECOMPILED_TARGET").ok());
 
 // node
 pub static RTX_NODE_CONCURRENCY: Lazy<usize> = Lazy::new(|| {
@@ -109,6 +125,11 @@ pub static RTX_NODE_CONCURRENCY: Lazy<usize> = Lazy::new(|| {
 pub static RTX_NODE_VERBOSE_INSTALL: Lazy<bool> =
     Lazy::new(|| var_is_true("RTX_NODE_VERBOSE_INSTALL"));
 pub static RTX_NODE_FORCE_COMPILE: Lazy<bool> = Lazy::new(|| var_is_true("RTX_NODE_FORCE_COMPILE"));
+pub static RTX_NODE_MIRROR_URL: Lazy<String> = Lazy::new(|| {
+    var("RTX_NODE_MIRROR_URL")
+        .or_else(|_| var("NODEJS_ORG_MIRROR"))
+        .unwrap_or_else(|_| "https://nodejs.org/dist/".into())
+});
 pub static RTX_NODE_DEFAULT_PACKAGES_FILE: Lazy<PathBuf> = Lazy::new(|| {
     var_path("RTX_NODE_DEFAULT_PACKAGES_FILE").unwrap_or_else(|| {
         let p = HOME.join(".default-nodejs-packages");
@@ -119,6 +140,37 @@ pub static RTX_NODE_DEFAULT_PACKAGES_FILE: Lazy<PathBuf> = Lazy::new(|| {
     })
 });
 
+// ruby
+pub static RTX_RUBY_VERBOSE_INSTALL: Lazy<bool> =
+    Lazy::new(|| var_is_true("RTX_RUBY_VERBOSE_INSTALL"));
+pub static RTX_RUBY_DEFAULT_PACKAGES_FILE: Lazy<PathBuf> = Lazy::new(|| {
+    var_path("RTX_RUBY_DEFAULT_PACKAGES_FILE").unwrap_or_else(|| HOME.join(".default-gems"))
+});
+
+// cargo
+pub static RTX_CARGO_INDEX_URL: Lazy<String> =
+    Lazy::new(|| var("RTX_CARGO_INDEX_URL").unwrap_or_else(|_| "https://index.crates.io".into()));
+
+// pipx
+pub static RTX_PIPX_INDEX_URL: Lazy<String> =
+    Lazy::new(|| var("RTX_PIPX_INDEX_URL").unwrap_or_else(|_| "https://pypi.org/simple".into()));
+
+// java
+pub static RTX_JAVA_METADATA_URL: Lazy<String> = Lazy::new(|| {
+    var("RTX_JAVA_METADATA_URL").unwrap_or_else(|_| {
+        "https://joschi.github.io/java-metadata/metadata/ga/{os}/{arch}.json".into()
+    })
+});
+
+// go
+pub static RTX_GO_DOWNLOAD_MIRROR: Lazy<String> = Lazy::new(|| {
+    var("RTX_GO_DOWNLOAD_MIRROR").unwrap_or_else(|_| "https://dl.google.com/go".into())
+});
+pub static RTX_GO_RELEASE_INDEX_URL: Lazy<String> = Lazy::new(|| {
+    var("RTX_GO_RELEASE_INDEX_URL")
+        .unwrap_or_else(|_| "https://go.dev/dl/?mode=json&include=all".into())
+});
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Confirm {
     Yes,
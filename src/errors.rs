@@ -1,4 +1,5 @@
 use std::process::ExitStatus;
+use std::time::Duration;
 
 use thiserror::Error;
 
@@ -11,10 +12,11 @@ pub enum Error {
     #[error("{0}@{1} not installed")]
     VersionNotInstalled(PluginName, String),
     #[error("{0}@{1} not found")]
-    #[allow(dead_code)]
     VersionNotFound(PluginName, String),
     #[error("{} exited with non-zero status: {}", .0, render_exit_status(.1))]
     ScriptFailed(String, Option<ExitStatus>),
+    #[error("{}{} timed out after {}", render_plugin(.1), .0, humantime::format_duration(*.2))]
+    ScriptTimeout(String, Option<PluginName>, Duration),
     #[error("Config file is not trusted.\nTrust it with `rtx trust`.")]
     UntrustedConfig(),
 }
@@ -25,3 +27,10 @@ fn render_exit_status(exit_status: &Option<ExitStatus>) -> String {
         None => "no exit status".into(),
     }
 }
+
+fn render_plugin(plugin: &Option<PluginName>) -> String {
+    match plugin {
+        Some(plugin) => format!("[{plugin}] "),
+        None => String::new(),
+    }
+}
@@ -1,9 +1,11 @@
+use std::fs::File;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use std::{fs, io};
 
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{Context, Result};
 use filetime::{set_file_times, FileTime};
+use flate2::read::GzDecoder;
 use std::os::unix::fs::symlink;
 use std::os::unix::prelude::*;
 
@@ -140,6 +142,15 @@ pub fn is_executable(path: &Path) -> bool {
     false
 }
 
+/// extracts a .tar.gz archive into dest
+pub fn untar(archive: &Path, dest: &Path) -> Result<()> {
+    debug!("tar -xzf {} -C {}", archive.display(), dest.display());
+    let f = File::open(archive)?;
+    let mut tar = tar::Archive::new(GzDecoder::new(f));
+    tar.unpack(dest)
+        .wrap_err_with(|| format!("failed to extract {}", display_path(archive)))
+}
+
 pub struct FindUp {
     current_dir: PathBuf,
     current_dir_filenames: Vec<String>,
@@ -36,6 +36,8 @@ impl Git {
     pub fn update(&self, gitref: Option<String>) -> Result<(String, String)> {
         let gitref = gitref.map_or_else(|| self.remote_default_branch(), Ok)?;
         debug!("updating {} to {}", self.dir.display(), gitref);
+        // read before fetching since fetching into the checked out branch moves HEAD
+        let prev_rev = self.current_sha()?;
         self.run_git_command(&[
             "fetch",
             "--prune",
@@ -43,7 +45,6 @@ impl Git {
             "origin",
             format!("{}:{}", gitref, gitref).as_str(),
         ])?;
-        let prev_rev = self.current_sha()?;
         self.run_git_command(&[
             "-c",
             "advice.detachedHead=false",
@@ -59,6 +60,38 @@ impl Git {
         Ok((prev_rev, post_rev))
     }
 
+    /// fetches a ref without changing the checkout and returns the sha it points to
+    pub fn fetch(&self, gitref: Option<String>) -> Result<String> {
+        let gitref = gitref.map_or_else(|| self.remote_default_branch(), Ok)?;
+        debug!("fetching {} in {}", gitref, self.dir.display());
+        self.run_git_command(&["fetch", "origin", gitref.as_str()])?;
+        let sha = cmd!("git", "-C", &self.dir, "rev-parse", "FETCH_HEAD").read()?;
+        Ok(sha)
+    }
+
+    /// fetches a commit and checks it out detached, without a local branch named after it
+    pub fn checkout_sha(&self, sha: &str) -> Result<()> {
+        debug!("checking out {} in {}", sha, self.dir.display());
+        self.run_git_command(&["fetch", "origin", sha])?;
+        self.run_git_command(&[
+            "-c",
+            "advice.detachedHead=false",
+            "checkout",
+            "--force",
+            "--detach",
+            sha,
+        ])?;
+        touch_dir(&self.dir)?;
+        Ok(())
+    }
+
+    /// the subjects of the commits after `from` up to `to`, newest first
+    pub fn log_subjects(&self, from: &str, to: &str) -> Result<Vec<String>> {
+        let range = format!("{from}..{to}");
+        let output = cmd!("git", "-C", &self.dir, "log", "--format=%s", range).read()?;
+        Ok(output.lines().map(|l| l.to_string()).collect())
+    }
+
     pub fn clone(&self, url: &str) -> Result<()> {
         debug!("cloning {} to {}", url, self.dir.display());
         if let Some(parent) = self.dir.parent() {
@@ -75,6 +108,21 @@ impl Git {
         Ok(())
     }
 
+    /// the checked out branch, None if HEAD is detached
+    pub fn current_branch(&self) -> Option<String> {
+        cmd!(
+            "git",
+            "-C",
+            &self.dir,
+            "symbolic-ref",
+            "-q",
+            "--short",
+            "HEAD"
+        )
+        .read()
+        .ok()
+    }
+
     pub fn current_sha(&self) -> Result<String> {
         let sha = cmd!("git", "-C", &self.dir, "rev-parse", "HEAD").read()?;
         debug!("current sha for {}: {}", self.dir.display(), &sha);
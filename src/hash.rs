@@ -1,5 +1,11 @@
 use std::collections::hash_map::DefaultHasher;
+use std::fs::File;
 use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::Path;
+
+use color_eyre::eyre::{bail, Result};
+use sha2::{Digest, Sha256};
 
 pub fn hash_to_str<T: Hash>(t: &T) -> String {
     let mut s = DefaultHasher::new();
@@ -8,12 +14,44 @@ pub fn hash_to_str<T: Hash>(t: &T) -> String {
     format!("{bytes:x}")
 }
 
+pub fn file_hash_sha256(path: &Path) -> Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher)?;
+    let hash = hasher.finalize();
+    Ok(format!("{hash:x}"))
+}
+
+pub fn ensure_checksum_sha256(path: &Path, checksum: &str) -> Result<()> {
+    let actual = file_hash_sha256(path)?;
+    if actual != checksum {
+        bail!(
+            "checksum mismatch for {}: expected {}, got {}",
+            path.display(),
+            checksum,
+            actual
+        );
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::dirs;
+
     use super::*;
 
     #[test]
     fn test_hash_to_str() {
         assert_eq!(hash_to_str(&"foo"), "3e8b8c44c3ca73b7");
     }
+
+    #[test]
+    fn test_file_hash_sha256() {
+        let path = dirs::HOME.join("fixtures/shorthands.toml");
+        let hash = file_hash_sha256(&path).unwrap();
+        assert_eq!(hash.len(), 64);
+        assert!(ensure_checksum_sha256(&path, &hash).is_ok());
+        assert!(ensure_checksum_sha256(&path, "0000").is_err());
+    }
 }
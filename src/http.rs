@@ -1,9 +1,17 @@
-use color_eyre::eyre::Result;
+use std::fs;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{eyre, Result};
 use reqwest::blocking::RequestBuilder;
-use reqwest::IntoUrl;
+use reqwest::{IntoUrl, Url};
+
+use crate::file;
+use crate::file::display_path;
 
 pub struct Client {
     reqwest: reqwest::blocking::Client,
+    bearer_token: Option<String>,
 }
 
 impl Client {
@@ -11,10 +19,63 @@ impl Client {
         let reqwest = reqwest::blocking::ClientBuilder::new()
             .user_agent(format!("rtx/{}", env!("CARGO_PKG_VERSION")))
             .build()?;
-        Ok(Self { reqwest })
+        Ok(Self {
+            reqwest,
+            bearer_token: None,
+        })
+    }
+
+    /// sends the token with every request, e.g.: GITHUB_API_TOKEN to the GitHub API
+    pub fn with_bearer_auth(mut self, token: Option<String>) -> Self {
+        self.bearer_token = token;
+        self
     }
 
     pub fn get<U: IntoUrl>(&self, url: U) -> RequestBuilder {
-        self.reqwest.get(url)
+        let req = self.reqwest.get(url);
+        match &self.bearer_token {
+            Some(token) => req.bearer_auth(token),
+            None => req,
+        }
+    }
+
+    /// fetches the body of url as a string, file:// urls are read from disk
+    pub fn get_text<U: AsRef<str>>(&self, url: U) -> Result<String> {
+        let url = Url::parse(url.as_ref())?;
+        if let Some(path) = local_path(&url)? {
+            trace!("reading {}", display_path(&path));
+            return Ok(fs::read_to_string(path)?);
+        }
+        debug!("GET {}", &url);
+        Ok(self.get(url).send()?.error_for_status()?.text()?)
+    }
+
+    pub fn download_file<U: AsRef<str>>(&self, url: U, path: &Path) -> Result<()> {
+        let url = Url::parse(url.as_ref())?;
+        if let Some(parent) = path.parent() {
+            file::create_dir_all(parent)?;
+        }
+        if let Some(local) = local_path(&url)? {
+            debug!("cp {} {}", display_path(&local), display_path(path));
+            fs::copy(local, path)?;
+            return Ok(());
+        }
+        debug!("GET {} to {}", &url, display_path(path));
+        let mut resp = self.get(url).send()?.error_for_status()?;
+        let mut file = File::create(path)?;
+        resp.copy_to(&mut file)?;
+        Ok(())
+    }
+}
+
+fn local_path(url: &Url) -> Result<Option<PathBuf>> {
+    match url.scheme() {
+        "file" => {
+            let path = url
+                .to_file_path()
+                .map_err(|_| eyre!("invalid file url: {}", url))?;
+            Ok(Some(path))
+        }
+        _ => Ok(None),
     }
 }
@@ -14,7 +14,6 @@ mod build_time;
 mod cache;
 pub mod cmd;
 mod config;
-mod default_shorthands;
 mod direnv;
 mod dirs;
 mod duration;
@@ -30,6 +29,7 @@ mod hook_env;
 mod http;
 mod lock_file;
 mod plugins;
+mod registry;
 mod runtime_symlinks;
 mod shell;
 mod shims;
@@ -24,7 +24,6 @@ mod cache;
 mod cli;
 mod cmd;
 mod config;
-mod default_shorthands;
 mod direnv;
 mod dirs;
 pub mod duration;
@@ -40,6 +39,7 @@ mod http;
 mod lock_file;
 mod logger;
 mod plugins;
+mod registry;
 mod runtime_symlinks;
 mod shell;
 mod shims;
@@ -88,6 +88,7 @@ fn handle_ctrlc() {
     ctrlc::set_handler(move || {
         let _ = Term::stderr().show_cursor();
         debug!("Ctrl-C pressed, exiting...");
+        cmd::kill_process_groups();
         exit(1);
     })
     .expect("Error setting Ctrl-C handler");
@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+
+use color_eyre::eyre::{eyre, Result};
+use itertools::Itertools;
+use serde_derive::Deserialize;
+use versions::Versioning;
+
+use crate::cache::CacheManager;
+use crate::cmd::CmdLineRunner;
+use crate::config::Config;
+use crate::env::RTX_CARGO_INDEX_URL;
+use crate::plugins::backends::{dependency_env, dependency_error, remote_cache};
+use crate::plugins::{Plugin, PluginName};
+use crate::toolset::{ToolVersion, ToolVersionOptions, ToolVersionRequest};
+use crate::ui::progress_report::ProgressReport;
+use crate::{file, http};
+
+/// builds crates with `cargo install` and the rust rtx resolves, e.g.: "cargo:cargo-nextest"
+#[derive(Debug)]
+pub struct CargoPlugin {
+    pub name: PluginName,
+    crate_name: String,
+    remote_version_cache: CacheManager<Vec<String>>,
+}
+
+impl CargoPlugin {
+    pub fn new(name: PluginName, crate_name: &str) -> Self {
+        Self {
+            remote_version_cache: remote_cache(&name, "remote_versions.msgpack.z"),
+            crate_name: crate_name.to_string(),
+            name,
+        }
+    }
+
+    fn fetch_remote_versions(&self) -> Result<Vec<String>> {
+        let http = http::Client::new()?;
+        let body = http.get_text(index_url(&self.crate_name))?;
+        parse_index(&body)
+    }
+}
+
+impl Plugin for CargoPlugin {
+    fn name(&self) -> &PluginName {
+        &self.name
+    }
+
+    fn list_remote_versions(&self, _config: &Config) -> Result<Vec<String>> {
+        self.remote_version_cache
+            .get_or_try_init(|| self.fetch_remote_versions())
+            .cloned()
+    }
+
+    fn resolves_remote_versions(&self, opts: &ToolVersionOptions) -> bool {
+        !opts.contains_key("git")
+    }
+
+    fn install_version(
+        &self,
+        config: &Config,
+        tv: &ToolVersion,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        if matches!(tv.request, ToolVersionRequest::Ref(..)) {
+            return Err(eyre!("Ref versions not supported for {}", self.name));
+        }
+        pr.set_message(format!("cargo install {}@{}", self.crate_name, tv.version));
+        let mut cmd = CmdLineRunner::new(&config.settings, "cargo");
+        cmd.with_pr(pr)
+            .envs(cargo_env(config)?)
+            .arg("install")
+            .arg("--root")
+            .arg(tv.install_path());
+        match tv.opts.get("git") {
+            Some(git) => {
+                cmd.arg("--git").arg(git);
+                if let Some(rev) = tv.opts.get("rev") {
+                    cmd.arg("--rev").arg(rev);
+                }
+            }
+            None => {
+                cmd.arg("--version").arg(&tv.version);
+            }
+        }
+        cmd.arg(&self.crate_name);
+        cmd.execute()
+            .map_err(|err| dependency_error(err, "cargo", &self.crate_name, "rust@latest"))
+    }
+}
+
+fn cargo_env(config: &Config) -> Result<BTreeMap<String, String>> {
+    let mut env: BTreeMap<String, String> = std::env::vars().collect();
+    env.extend(dependency_env(config, &["rust"])?);
+    Ok(env)
+}
+
+/// the url of a crate's entry in a sparse index, the index can also be a local directory
+/// see https://doc.rust-lang.org/cargo/reference/registry-index.html#index-files
+fn index_url(crate_name: &str) -> String {
+    let name = crate_name.to_lowercase();
+    let path = match name.len() {
+        1 => format!("1/{name}"),
+        2 => format!("2/{name}"),
+        3 => format!("3/{}/{name}", &name[..1]),
+        _ => format!("{}/{}/{name}", &name[..2], &name[2..4]),
+    };
+    let index = &*RTX_CARGO_INDEX_URL;
+    let index = match index.contains("://") {
+        true => index.to_string(),
+        false => format!("file://{}", file::replace_path(index).display()),
+    };
+    format!("{}/{path}", index.trim_end_matches('/'))
+}
+
+#[derive(Debug, Deserialize)]
+struct IndexEntry {
+    vers: String,
+    #[serde(default)]
+    yanked: bool,
+}
+
+/// each line of an index file is a json object for one version
+fn parse_index(body: &str) -> Result<Vec<String>> {
+    let mut versions = vec![];
+    for line in body.lines().filter(|l| !l.trim().is_empty()) {
+        let entry: IndexEntry = serde_json::from_str(line)?;
+        if !entry.yanked {
+            versions.push(entry.vers);
+        }
+    }
+    Ok(versions
+        .into_iter()
+        .unique()
+        .sorted_by_cached_key(|v| Versioning::new(v))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::tool::Tool;
+
+    use super::*;
+
+    #[test]
+    fn test_index_url() {
+        assert!(index_url("a").ends_with("/1/a"));
+        assert!(index_url("cc").ends_with("/2/cc"));
+        assert!(index_url("syn").ends_with("/3/s/syn"));
+        assert!(index_url("Cargo-Nextest").ends_with("/ca/rg/cargo-nextest"));
+    }
+
+    #[test]
+    fn test_parse_index() {
+        let body = indoc! {r#"
+            {"name":"just","vers":"1.14.0","deps":[],"cksum":"abc","features":{},"yanked":false}
+            {"name":"just","vers":"1.13.0","deps":[],"cksum":"abc","features":{},"yanked":false}
+            {"name":"just","vers":"1.15.0","deps":[],"cksum":"abc","features":{},"yanked":true}
+        "#};
+        assert_eq!(parse_index(body).unwrap(), vec!["1.13.0", "1.14.0"]);
+    }
+
+    #[test]
+    fn test_resolve_git() {
+        let config = Config::default();
+        let name = PluginName::from("cargo:just");
+        let tool = Tool::new(
+            name.clone(),
+            Box::new(CargoPlugin::new(name.clone(), "just")),
+        );
+        let opts =
+            ToolVersionOptions::from([("git".into(), "https://github.com/casey/just".into())]);
+        let request = ToolVersionRequest::Version(name, "1.14".into());
+        let tv = request.resolve(&config, &tool, opts, false).unwrap();
+        assert_eq!(tv.version, "1.14");
+    }
+}
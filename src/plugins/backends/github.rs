@@ -0,0 +1,341 @@
+use std::collections::HashSet;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{eyre, Result};
+use itertools::Itertools;
+use once_cell::sync::OnceCell;
+use regex::Regex;
+use serde_derive::{Deserialize, Serialize};
+use versions::Versioning;
+
+use crate::cache::CacheManager;
+use crate::config::Config;
+use crate::env::{GITHUB_API_TOKEN, RTX_GITHUB_API_URL};
+use crate::plugins::backends::remote_cache;
+use crate::plugins::{is_prerelease, Plugin, PluginName};
+use crate::toolset::{ToolVersion, ToolVersionOptions, ToolVersionRequest};
+use crate::ui::progress_report::ProgressReport;
+use crate::{file, hash, http};
+
+/// installs tools from the assets attached to GitHub releases
+/// e.g.: "github:BurntSushi/ripgrep"
+#[derive(Debug)]
+pub struct GithubPlugin {
+    pub name: PluginName,
+    repo: String,
+    release_cache: CacheManager<Vec<GithubRelease>>,
+    /// the versions of the releases marked as prereleases on GitHub
+    prereleases: OnceCell<HashSet<String>>,
+}
+
+impl GithubPlugin {
+    pub fn new(name: PluginName, repo: &str) -> Self {
+        Self {
+            release_cache: remote_cache(&name, "releases.msgpack.z"),
+            prereleases: OnceCell::new(),
+            repo: repo.to_string(),
+            name,
+        }
+    }
+
+    fn releases(&self) -> Result<&Vec<GithubRelease>> {
+        self.release_cache.get_or_try_init(|| self.fetch_releases())
+    }
+
+    fn prereleases(&self) -> Result<&HashSet<String>> {
+        self.prereleases.get_or_try_init(|| {
+            let releases = self.releases()?;
+            Ok(releases
+                .iter()
+                .filter(|r| r.prerelease)
+                .map(|r| tag_to_version(&r.tag_name))
+                .collect())
+        })
+    }
+
+    fn fetch_releases(&self) -> Result<Vec<GithubRelease>> {
+        let http = http::Client::new()?.with_bearer_auth(GITHUB_API_TOKEN.clone());
+        let mut releases = vec![];
+        for page in 1..=10 {
+            let url = format!(
+                "{}/repos/{}/releases?per_page=100&page={page}",
+                RTX_GITHUB_API_URL.trim_end_matches('/'),
+                self.repo
+            );
+            let body = http.get_text(url)?;
+            let page: Vec<GithubRelease> = serde_json::from_str(&body)?;
+            let done = page.len() < 100;
+            releases.extend(page.into_iter().filter(|r| !r.draft));
+            if done {
+                break;
+            }
+        }
+        Ok(releases)
+    }
+
+    fn find_release(&self, tv: &ToolVersion) -> Result<&GithubRelease> {
+        self.releases()?
+            .iter()
+            .find(|r| tag_to_version(&r.tag_name) == tv.version)
+            .ok_or_else(|| eyre!("no release found for {} in {}", tv, self.repo))
+    }
+
+    fn download(
+        &self,
+        tv: &ToolVersion,
+        asset: &GithubAsset,
+        release: &GithubRelease,
+        pr: &ProgressReport,
+    ) -> Result<PathBuf> {
+        let http = http::Client::new()?;
+        let asset_path = tv.download_path().join(&asset.name);
+
+        pr.set_message(format!("downloading {}", &asset.browser_download_url));
+        http.download_file(&asset.browser_download_url, &asset_path)?;
+
+        let checksum_name = format!("{}.sha256", asset.name);
+        if let Some(checksum_asset) = release.assets.iter().find(|a| a.name == checksum_name) {
+            pr.set_message(format!("verifying {}", &asset.name));
+            let checksum = http.get_text(&checksum_asset.browser_download_url)?;
+            let checksum = checksum.split_whitespace().next().unwrap_or_default();
+            hash::ensure_checksum_sha256(&asset_path, checksum)?;
+        }
+        Ok(asset_path)
+    }
+
+    fn install(&self, tv: &ToolVersion, asset_path: &Path, pr: &ProgressReport) -> Result<()> {
+        let filename = file::basename(asset_path).unwrap_or_default();
+        pr.set_message(format!("installing {}", &filename));
+        if filename.ends_with(".tar.gz") || filename.ends_with(".tgz") {
+            file::untar(asset_path, &tv.install_path())?;
+        } else if !regex!(r"\.(zip|tar|tar\.xz|tar\.bz2|txz|gz|xz|bz2|7z)$").is_match(&filename) {
+            // a bare executable
+            let bin_name = tv
+                .opts
+                .get("bin")
+                .cloned()
+                .unwrap_or_else(|| self.repo_name().to_string());
+            let bin_path = tv.install_path().join("bin").join(bin_name);
+            file::create_dir_all(bin_path.parent().unwrap())?;
+            fs::copy(asset_path, &bin_path)?;
+            fs::set_permissions(&bin_path, fs::Permissions::from_mode(0o755))?;
+        } else {
+            return Err(eyre!("unsupported asset format: {}", filename));
+        }
+        Ok(())
+    }
+
+    fn repo_name(&self) -> &str {
+        self.repo.rsplit('/').next().unwrap_or(&self.repo)
+    }
+}
+
+impl Plugin for GithubPlugin {
+    fn name(&self) -> &PluginName {
+        &self.name
+    }
+
+    fn list_remote_versions(&self, _config: &Config) -> Result<Vec<String>> {
+        let versions = self
+            .releases()?
+            .iter()
+            .map(|r| tag_to_version(&r.tag_name))
+            .unique()
+            .sorted_by_cached_key(|v| Versioning::new(v))
+            .collect();
+        Ok(versions)
+    }
+
+    /// releases marked as prereleases on GitHub, or with a version that looks like one
+    fn is_prerelease(&self, version: &str) -> bool {
+        let marked = self
+            .prereleases()
+            .map_or(false, |prereleases| prereleases.contains(version));
+        marked || is_prerelease(version)
+    }
+
+    fn install_version(
+        &self,
+        _config: &Config,
+        tv: &ToolVersion,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        if matches!(tv.request, ToolVersionRequest::Ref(..)) {
+            return Err(eyre!("Ref versions not supported for {}", self.name));
+        }
+        let release = self.find_release(tv)?;
+        let asset = pick_asset(&release.assets, &tv.opts, &tv.version)?
+            .ok_or_else(|| eyre!("no asset found for {} matching {}-{}", tv, os(), arch()))?;
+        let asset_path = self.download(tv, asset, release, pr)?;
+        self.install(tv, &asset_path, pr)?;
+        Ok(())
+    }
+
+    fn list_bin_paths(&self, _config: &Config, tv: &ToolVersion) -> Result<Vec<PathBuf>> {
+        let mut bin_paths = vec![];
+        find_bin_dirs(&tv.install_path(), 3, &mut bin_paths)?;
+        Ok(bin_paths)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    prerelease: bool,
+    #[serde(default)]
+    assets: Vec<GithubAsset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GithubAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// "v1.2.3" -> "1.2.3", other tags are used as-is
+fn tag_to_version(tag: &str) -> String {
+    match regex!(r"^v[0-9]").is_match(tag) {
+        true => tag[1..].to_string(),
+        false => tag.to_string(),
+    }
+}
+
+/// picks the release asset for this platform
+/// the `asset_pattern` tool option overrides the detection, it is a regex matched against the
+/// asset name where `{os}`, `{arch}` and `{version}` are replaced
+fn pick_asset<'a>(
+    assets: &'a [GithubAsset],
+    opts: &ToolVersionOptions,
+    version: &str,
+) -> Result<Option<&'a GithubAsset>> {
+    if let Some(pattern) = opts.get("asset_pattern") {
+        let pattern = pattern
+            .replace("{os}", os())
+            .replace("{arch}", arch())
+            .replace("{version}", &regex::escape(version));
+        let re = Regex::new(&format!("^{pattern}$"))?;
+        return Ok(assets.iter().find(|a| re.is_match(&a.name)));
+    }
+    let os_re = match std::env::consts::OS {
+        "macos" => regex!(r"(?i)(darwin|macos|apple|osx)"),
+        _ => regex!(r"(?i)linux"),
+    };
+    let arch_re = match std::env::consts::ARCH {
+        "aarch64" => regex!(r"(?i)(aarch64|arm64)"),
+        _ => regex!(r"(?i)(x86_64|x86-64|amd64|x64)"),
+    };
+    let ignore_re =
+        regex!(r"(?i)\.(sha256|sha512|sha256sum|md5|sig|asc|pem|sbom|txt|json|deb|rpm|apk|msi)$");
+    let asset = assets
+        .iter()
+        .filter(|a| os_re.is_match(&a.name) && arch_re.is_match(&a.name))
+        .filter(|a| !ignore_re.is_match(&a.name))
+        // prefer tarballs, then bare executables
+        .min_by_key(|a| {
+            if regex!(r"\.(tar\.gz|tgz)$").is_match(&a.name) {
+                0
+            } else if !a.name.contains('.') {
+                1
+            } else {
+                2
+            }
+        });
+    Ok(asset)
+}
+
+/// finds the directories under dir that contain executables
+fn find_bin_dirs(dir: &Path, depth: usize, bin_dirs: &mut Vec<PathBuf>) -> Result<()> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+    let entries = fs::read_dir(dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .sorted()
+        .collect_vec();
+    if entries
+        .iter()
+        .any(|p| p.is_file() && file::is_executable(p))
+    {
+        bin_dirs.push(dir.to_path_buf());
+    }
+    if depth > 0 {
+        for subdir in entries.iter().filter(|p| p.is_dir()) {
+            find_bin_dirs(subdir, depth - 1, bin_dirs)?;
+        }
+    }
+    Ok(())
+}
+
+fn os() -> &'static str {
+    match std::env::consts::OS {
+        "macos" => "darwin",
+        os => os,
+    }
+}
+
+fn arch() -> &'static str {
+    match std::env::consts::ARCH {
+        "x86_64" => "amd64",
+        "aarch64" => "arm64",
+        arch => arch,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assets(names: &[&str]) -> Vec<GithubAsset> {
+        names
+            .iter()
+            .map(|name| GithubAsset {
+                name: name.to_string(),
+                browser_download_url: format!("https://example.com/{name}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_tag_to_version() {
+        assert_eq!(tag_to_version("v1.2.3"), "1.2.3");
+        assert_eq!(tag_to_version("13.0.0"), "13.0.0");
+        assert_eq!(tag_to_version("jq-1.7"), "jq-1.7");
+    }
+
+    #[test]
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    fn test_pick_asset() {
+        let assets = assets(&[
+            "ripgrep-13.0.0-x86_64-apple-darwin.tar.gz",
+            "ripgrep-13.0.0-x86_64-unknown-linux-musl.tar.gz.sha256",
+            "ripgrep-13.0.0-x86_64-unknown-linux-musl.tar.gz",
+            "ripgrep_13.0.0_amd64.deb",
+        ]);
+        let asset = pick_asset(&assets, &ToolVersionOptions::new(), "13.0.0").unwrap();
+        assert_eq!(
+            asset.unwrap().name,
+            "ripgrep-13.0.0-x86_64-unknown-linux-musl.tar.gz"
+        );
+
+        let assets = self::assets(&["jq-linux-amd64", "jq-macos-arm64", "jq-1.7.tar.gz"]);
+        let asset = pick_asset(&assets, &ToolVersionOptions::new(), "1.7").unwrap();
+        assert_eq!(asset.unwrap().name, "jq-linux-amd64");
+    }
+
+    #[test]
+    fn test_pick_asset_pattern() {
+        let assets = assets(&["tool-1.0.0-{os}-{arch}.tar.gz", "tool-1.0.0-custom.tar.gz"]);
+        let opts = ToolVersionOptions::from([(
+            "asset_pattern".to_string(),
+            r"tool-{version}-custom\.tar\.gz".to_string(),
+        )]);
+        let asset = pick_asset(&assets, &opts, "1.0.0").unwrap();
+        assert_eq!(asset.unwrap().name, "tool-1.0.0-custom.tar.gz");
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::BTreeMap;
+
+use color_eyre::eyre::{eyre, Result};
+use itertools::Itertools;
+use versions::Versioning;
+
+use crate::cache::CacheManager;
+use crate::cmd;
+use crate::cmd::CmdLineRunner;
+use crate::config::Config;
+use crate::plugins::backends::{dependency_env, dependency_error, remote_cache};
+use crate::plugins::{Plugin, PluginName};
+use crate::toolset::{ToolVersion, ToolVersionRequest};
+use crate::ui::progress_report::ProgressReport;
+
+/// installs go modules with `go install` and the go version rtx resolves
+/// e.g.: "go:golang.org/x/tools/gopls"
+#[derive(Debug)]
+pub struct GoModulePlugin {
+    pub name: PluginName,
+    module: String,
+    remote_version_cache: CacheManager<Vec<String>>,
+}
+
+impl GoModulePlugin {
+    pub fn new(name: PluginName, module: &str) -> Self {
+        Self {
+            remote_version_cache: remote_cache(&name, "remote_versions.msgpack.z"),
+            module: module.to_string(),
+            name,
+        }
+    }
+
+    /// the package to install is often a subdirectory of the module that is versioned,
+    /// e.g.: "github.com/golangci/golangci-lint/cmd/golangci-lint", so this walks up the path
+    /// until `go list` finds a module
+    fn fetch_remote_versions(&self, config: &Config) -> Result<Vec<String>> {
+        let env = go_env(config)?;
+        let mut module = self.module.as_str();
+        loop {
+            let output = cmd!("go", "list", "-m", "-versions", module)
+                .full_env(&env)
+                .stdout_capture()
+                .stderr_capture()
+                .unchecked()
+                .run()
+                .map_err(|err| dependency_error(err.into(), "go", &self.module, "go@1.20"))?;
+            if output.status.success() {
+                return Ok(parse_versions(&String::from_utf8_lossy(&output.stdout)));
+            }
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            match module.rsplit_once('/') {
+                Some((parent, _)) if parent.contains('/') && is_module_not_found(&stderr) => {
+                    module = parent
+                }
+                _ => {
+                    return Err(eyre!(
+                        "failed to list versions of {}: {}",
+                        self.module,
+                        stderr.trim()
+                    ))
+                }
+            }
+        }
+    }
+}
+
+impl Plugin for GoModulePlugin {
+    fn name(&self) -> &PluginName {
+        &self.name
+    }
+
+    fn list_remote_versions(&self, config: &Config) -> Result<Vec<String>> {
+        self.remote_version_cache
+            .get_or_try_init(|| self.fetch_remote_versions(config))
+            .cloned()
+    }
+
+    fn install_version(
+        &self,
+        config: &Config,
+        tv: &ToolVersion,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        if matches!(tv.request, ToolVersionRequest::Ref(..)) {
+            return Err(eyre!("Ref versions not supported for {}", self.name));
+        }
+        pr.set_message(format!("go install {}@v{}", self.module, tv.version));
+        let mut cmd = CmdLineRunner::new(&config.settings, "go");
+        cmd.with_pr(pr)
+            .envs(go_env(config)?)
+            .env("GOBIN", tv.install_path().join("bin"))
+            .arg("install")
+            .arg(format!("{}@v{}", self.module, tv.version));
+        cmd.execute()
+            .map_err(|err| dependency_error(err, "go", &self.module, "go@1.20"))
+    }
+}
+
+/// GOPROXY and the rest of the user's go settings are passed through from the environment
+fn go_env(config: &Config) -> Result<BTreeMap<String, String>> {
+    let mut env: BTreeMap<String, String> = std::env::vars().collect();
+    env.extend(dependency_env(config, &["go", "golang"])?);
+    Ok(env)
+}
+
+/// the proxy responds with "404 Not Found" or "410 Gone" for paths that are not modules, e.g.:
+/// "go: module example.com/a/cmd/b: reading https://proxy.golang.org/...: 404 Not Found"
+fn is_module_not_found(stderr: &str) -> bool {
+    regex!(r"(?i)(not found|410 gone|no matching versions)").is_match(stderr)
+}
+
+/// e.g.: "golang.org/x/tools/gopls v0.1.0 v0.2.0" -> ["0.1.0", "0.2.0"]
+fn parse_versions(output: &str) -> Vec<String> {
+    output
+        .split_whitespace()
+        .skip(1)
+        .map(|v| v.trim_start_matches('v').to_string())
+        .unique()
+        .sorted_by_cached_key(|v| Versioning::new(v))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_versions() {
+        assert_eq!(
+            parse_versions("golang.org/x/tools/gopls v0.12.0 v0.11.0 v0.12.4\n"),
+            vec!["0.11.0", "0.12.0", "0.12.4"]
+        );
+        assert!(parse_versions("example.com/untagged\n").is_empty());
+    }
+
+    #[test]
+    fn test_is_module_not_found() {
+        assert!(is_module_not_found(
+            "go: module github.com/golangci/golangci-lint/cmd/golangci-lint: reading https://proxy.golang.org/github.com/golangci/golangci-lint/cmd/golangci-lint/@v/list: 404 Not Found"
+        ));
+        assert!(!is_module_not_found(
+            "go: module golang.org/x/tools: Get \"https://proxy.golang.org/golang.org/x/tools/@v/list\": dial tcp: lookup proxy.golang.org: no such host"
+        ));
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::time::Duration;
+
+use color_eyre::eyre::{Report, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::cache::CacheManager;
+use crate::config::Config;
+use crate::dirs;
+use crate::env::{PREFER_STALE, RTX_EXE};
+use crate::plugins::backends::cargo::CargoPlugin;
+use crate::plugins::backends::github::GithubPlugin;
+use crate::plugins::backends::go::GoModulePlugin;
+use crate::plugins::backends::npm::NpmPlugin;
+use crate::plugins::backends::pipx::PipxPlugin;
+use crate::plugins::{plugin_dirname, Plugin, PluginName};
+use crate::toolset::ToolsetBuilder;
+
+mod cargo;
+mod github;
+mod go;
+mod npm;
+mod pipx;
+
+/// builds the plugin for tools named like "github:BurntSushi/ripgrep"
+/// returns None if the name does not use a backend prefix
+pub fn build(name: &PluginName) -> Option<Box<dyn Plugin>> {
+    match name.split_once(':') {
+        Some(("cargo", crate_name)) => Some(Box::new(CargoPlugin::new(name.clone(), crate_name))),
+        Some(("github", repo)) => Some(Box::new(GithubPlugin::new(name.clone(), repo))),
+        Some(("go", module)) => Some(Box::new(GoModulePlugin::new(name.clone(), module))),
+        Some(("npm", package)) => Some(Box::new(NpmPlugin::new(name.clone(), package))),
+        Some(("pipx", package)) => Some(Box::new(PipxPlugin::new(name.clone(), package))),
+        _ => None,
+    }
+}
+
+/// caches what a backend fetches about its remote versions, e.g.: "remote_versions.msgpack.z"
+/// it is refetched after 12 hours unless the command prefers stale data
+fn remote_cache<T>(name: &PluginName, filename: &str) -> CacheManager<T>
+where
+    T: Clone + Serialize + DeserializeOwned,
+{
+    let fresh_duration = if *PREFER_STALE {
+        None
+    } else {
+        Some(Duration::from_secs(60 * 60 * 12)) // 12 hours
+    };
+    CacheManager::new(dirs::CACHE.join(plugin_dirname(name)).join(filename))
+        .with_fresh_duration(fresh_duration)
+        .with_fresh_file(RTX_EXE.clone())
+}
+
+/// the environment with the versions rtx resolves for plugins, e.g.: "node" for npm
+/// this is used to run package managers with the runtime the user has configured
+fn dependency_env(config: &Config, plugins: &[&str]) -> Result<BTreeMap<String, String>> {
+    let mut ts = ToolsetBuilder::new().build_unresolved(config)?;
+    ts.versions.retain(|p, _| plugins.contains(&p.as_str()));
+    for tvl in ts.versions.values_mut() {
+        tvl.resolve(config, false);
+    }
+    Ok(ts.env_with_path(config))
+}
+
+/// suggests installing the runtime if the package manager itself could not be found, e.g.:
+/// "failed to run npm for prettier, is node installed? e.g.: rtx use node@20"
+fn dependency_error(err: Report, program: &str, name: &str, dependency: &str) -> Report {
+    let not_found = err
+        .downcast_ref::<io::Error>()
+        .map_or(false, |err| err.kind() == io::ErrorKind::NotFound);
+    if !not_found {
+        return err;
+    }
+    let runtime = dependency.split('@').next().unwrap_or(dependency);
+    err.wrap_err(format!(
+        "failed to run {program} for {name}, is {runtime} installed? e.g.: rtx use {dependency}"
+    ))
+}
@@ -0,0 +1,105 @@
+use std::collections::BTreeMap;
+
+use color_eyre::eyre::{eyre, Result};
+
+use crate::cache::CacheManager;
+use crate::cmd;
+use crate::cmd::CmdLineRunner;
+use crate::config::Config;
+use crate::plugins::backends::{dependency_env, dependency_error, remote_cache};
+use crate::plugins::{Plugin, PluginName};
+use crate::toolset::{ToolVersion, ToolVersionRequest};
+use crate::ui::progress_report::ProgressReport;
+
+/// installs npm packages into their own prefix with the node rtx resolves, e.g.: "npm:prettier"
+#[derive(Debug)]
+pub struct NpmPlugin {
+    pub name: PluginName,
+    package: String,
+    remote_version_cache: CacheManager<Vec<String>>,
+}
+
+impl NpmPlugin {
+    pub fn new(name: PluginName, package: &str) -> Self {
+        Self {
+            remote_version_cache: remote_cache(&name, "remote_versions.msgpack.z"),
+            package: package.to_string(),
+            name,
+        }
+    }
+
+    fn fetch_remote_versions(&self, config: &Config) -> Result<Vec<String>> {
+        let output = cmd!("npm", "view", &self.package, "versions", "--json")
+            .full_env(npm_env(config)?)
+            .read()
+            .map_err(|err| dependency_error(err.into(), "npm", &self.package, "node@20"))?;
+        parse_versions(&output)
+    }
+}
+
+impl Plugin for NpmPlugin {
+    fn name(&self) -> &PluginName {
+        &self.name
+    }
+
+    fn list_remote_versions(&self, config: &Config) -> Result<Vec<String>> {
+        self.remote_version_cache
+            .get_or_try_init(|| self.fetch_remote_versions(config))
+            .cloned()
+    }
+
+    fn install_version(
+        &self,
+        config: &Config,
+        tv: &ToolVersion,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        if matches!(tv.request, ToolVersionRequest::Ref(..)) {
+            return Err(eyre!("Ref versions not supported for {}", self.name));
+        }
+        pr.set_message(format!("npm install {}@{}", self.package, tv.version));
+        let mut cmd = CmdLineRunner::new(&config.settings, "npm");
+        cmd.with_pr(pr)
+            .envs(npm_env(config)?)
+            .arg("install")
+            .arg("--global")
+            .arg("--prefix")
+            .arg(tv.install_path())
+            .arg(format!("{}@{}", self.package, tv.version));
+        cmd.execute()
+            .map_err(|err| dependency_error(err, "npm", &self.package, "node@20"))
+    }
+}
+
+fn npm_env(config: &Config) -> Result<BTreeMap<String, String>> {
+    let mut env: BTreeMap<String, String> = std::env::vars().collect();
+    env.extend(dependency_env(config, &["node", "nodejs"])?);
+    Ok(env)
+}
+
+/// `npm view <pkg> versions --json` prints a string instead of an array if there is 1 version
+fn parse_versions(output: &str) -> Result<Vec<String>> {
+    let versions = match serde_json::from_str(output)? {
+        serde_json::Value::Array(versions) => versions
+            .into_iter()
+            .filter_map(|v| v.as_str().map(|v| v.to_string()))
+            .collect(),
+        serde_json::Value::String(version) => vec![version],
+        _ => vec![],
+    };
+    Ok(versions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_versions() {
+        assert_eq!(
+            parse_versions(r#"["1.0.0", "1.1.0"]"#).unwrap(),
+            vec!["1.0.0", "1.1.0"]
+        );
+        assert_eq!(parse_versions(r#""1.0.0""#).unwrap(), vec!["1.0.0"]);
+    }
+}
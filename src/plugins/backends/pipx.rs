@@ -0,0 +1,267 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use color_eyre::eyre::{eyre, Result};
+use itertools::Itertools;
+use versions::Versioning;
+
+use crate::cache::CacheManager;
+use crate::cmd::CmdLineRunner;
+use crate::config::Config;
+use crate::env::RTX_PIPX_INDEX_URL;
+use crate::plugins::backends::{dependency_env, dependency_error, remote_cache};
+use crate::plugins::{Plugin, PluginName};
+use crate::toolset::{ToolVersion, ToolVersionRequest};
+use crate::ui::progress_report::ProgressReport;
+use crate::{file, http};
+
+/// installs python packages into a virtualenv per version created with the python rtx resolves,
+/// e.g.: "pipx:black"
+#[derive(Debug)]
+pub struct PipxPlugin {
+    pub name: PluginName,
+    package: String,
+    remote_version_cache: CacheManager<Vec<String>>,
+}
+
+impl PipxPlugin {
+    pub fn new(name: PluginName, package: &str) -> Self {
+        Self {
+            remote_version_cache: remote_cache(&name, "remote_versions.msgpack.z"),
+            package: package.to_string(),
+            name,
+        }
+    }
+
+    fn fetch_remote_versions(&self) -> Result<Vec<String>> {
+        let project = normalize(&self.package);
+        let filenames = match index_url().strip_prefix("file://") {
+            Some(dir) => file::dir_files(&PathBuf::from(dir).join(&project))?,
+            None => {
+                let http = http::Client::new()?;
+                let url = format!("{}/{project}/", index_url().trim_end_matches('/'));
+                let body = http.get_text(url)?;
+                parse_simple_index(&body)
+            }
+        };
+        let versions = filenames
+            .iter()
+            .filter_map(|f| version_from_filename(&project, f))
+            .unique()
+            .sorted_by_cached_key(|v| Versioning::new(v))
+            .collect();
+        Ok(versions)
+    }
+
+    fn venv_path(&self, tv: &ToolVersion) -> PathBuf {
+        tv.install_path().join("venv")
+    }
+
+    fn create_venv(&self, config: &Config, tv: &ToolVersion, pr: &ProgressReport) -> Result<()> {
+        pr.set_message("creating virtualenv");
+        let mut cmd = CmdLineRunner::new(&config.settings, "python3");
+        cmd.with_pr(pr)
+            .envs(python_env(config)?)
+            .arg("-m")
+            .arg("venv")
+            .arg(self.venv_path(tv));
+        cmd.execute()
+            .map_err(|err| dependency_error(err, "python3", &self.package, "python@3.11"))
+    }
+
+    fn pip_install(&self, config: &Config, tv: &ToolVersion, pr: &ProgressReport) -> Result<()> {
+        pr.set_message(format!("pip install {}=={}", self.package, tv.version));
+        let mut cmd = CmdLineRunner::new(&config.settings, self.venv_path(tv).join("bin/pip"));
+        cmd.with_pr(pr).arg("install");
+        match index_url().strip_prefix("file://") {
+            // pip does not read directory listings from a local index, so it is passed as links
+            Some(dir) => cmd
+                .arg("--no-index")
+                .arg("--find-links")
+                .arg(PathBuf::from(dir).join(normalize(&self.package))),
+            None => cmd.arg("--index-url").arg(index_url()),
+        };
+        cmd.arg(format!("{}=={}", self.package, tv.version));
+        cmd.execute()
+    }
+
+    /// links the package's entry points into bin/ so the venv's own python/pip are not exposed
+    fn link_entry_points(&self, tv: &ToolVersion) -> Result<()> {
+        let bin_path = tv.install_path().join("bin");
+        file::create_dir_all(&bin_path)?;
+        let entry_points = self.entry_points(tv)?;
+        if entry_points.is_empty() {
+            warn!("{} has no entry points", self.package);
+        }
+        for name in entry_points {
+            let target = self.venv_path(tv).join("bin").join(&name);
+            file::make_symlink(&target, &bin_path.join(&name))?;
+        }
+        Ok(())
+    }
+
+    fn entry_points(&self, tv: &ToolVersion) -> Result<Vec<String>> {
+        let project = normalize(&self.package);
+        let lib = self.venv_path(tv).join("lib");
+        for python in file::dir_subdirs(&lib)? {
+            let site_packages = lib.join(python).join("site-packages");
+            for dir in file::dir_subdirs(&site_packages)? {
+                let name = match dir.strip_suffix(".dist-info") {
+                    Some(name) => name,
+                    None => continue,
+                };
+                let name = name.rsplit_once('-').map_or(name, |(name, _)| name);
+                if normalize(name) != project {
+                    continue;
+                }
+                let entry_points = site_packages.join(&dir).join("entry_points.txt");
+                return match entry_points.exists() {
+                    true => Ok(parse_entry_points(&fs::read_to_string(entry_points)?)),
+                    false => Ok(vec![]),
+                };
+            }
+        }
+        Err(eyre!(
+            "{} is not installed in {}",
+            self.package,
+            lib.display()
+        ))
+    }
+}
+
+impl Plugin for PipxPlugin {
+    fn name(&self) -> &PluginName {
+        &self.name
+    }
+
+    fn list_remote_versions(&self, _config: &Config) -> Result<Vec<String>> {
+        self.remote_version_cache
+            .get_or_try_init(|| self.fetch_remote_versions())
+            .cloned()
+    }
+
+    fn install_version(
+        &self,
+        config: &Config,
+        tv: &ToolVersion,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        if matches!(tv.request, ToolVersionRequest::Ref(..)) {
+            return Err(eyre!("Ref versions not supported for {}", self.name));
+        }
+        self.create_venv(config, tv, pr)?;
+        self.pip_install(config, tv, pr)?;
+        self.link_entry_points(tv)?;
+        Ok(())
+    }
+}
+
+fn python_env(config: &Config) -> Result<BTreeMap<String, String>> {
+    let mut env: BTreeMap<String, String> = std::env::vars().collect();
+    env.extend(dependency_env(config, &["python"])?);
+    Ok(env)
+}
+
+/// the index can be a url or a local directory laid out like a simple index
+fn index_url() -> String {
+    let index = &*RTX_PIPX_INDEX_URL;
+    match index.contains("://") {
+        true => index.to_string(),
+        false => format!("file://{}", file::replace_path(index).display()),
+    }
+}
+
+/// normalizes a project name as in PEP 503, e.g.: "Foo.Bar_baz" -> "foo-bar-baz"
+fn normalize(name: &str) -> String {
+    regex!(r"[-_.]+").replace_all(name, "-").to_lowercase()
+}
+
+/// the filenames linked from a PEP 503 simple index page
+fn parse_simple_index(body: &str) -> Vec<String> {
+    regex!(r##"<a [^>]*href="([^"#]+)[^"]*"[^>]*>"##)
+        .captures_iter(body)
+        .filter_map(|c| c[1].rsplit('/').next().map(|f| f.to_string()))
+        .collect()
+}
+
+/// e.g.: "black-23.1.0-py3-none-any.whl" or "black-23.1.0.tar.gz" -> "23.1.0"
+fn version_from_filename(project: &str, filename: &str) -> Option<String> {
+    let (name, version) = if let Some(wheel) = filename.strip_suffix(".whl") {
+        let mut parts = wheel.split('-');
+        (parts.next()?, parts.next()?)
+    } else {
+        let sdist = filename
+            .strip_suffix(".tar.gz")
+            .or_else(|| filename.strip_suffix(".zip"))?;
+        sdist.rsplit_once('-')?
+    };
+    (normalize(name) == project).then(|| version.to_string())
+}
+
+/// the names of the console and gui scripts in an entry_points.txt file
+fn parse_entry_points(contents: &str) -> Vec<String> {
+    let mut section = "";
+    let mut names = vec![];
+    for line in contents.lines().map(|l| l.trim()) {
+        if line.starts_with('[') {
+            section = line;
+        } else if section == "[console_scripts]" || section == "[gui_scripts]" {
+            if let Some((name, _)) = line.split_once('=') {
+                names.push(name.trim().to_string());
+            }
+        }
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_version_from_filename() {
+        assert_eq!(
+            version_from_filename("black", "black-23.1.0-py3-none-any.whl"),
+            Some("23.1.0".into())
+        );
+        assert_eq!(
+            version_from_filename("black", "black-23.1.0.tar.gz"),
+            Some("23.1.0".into())
+        );
+        assert_eq!(
+            version_from_filename("typing-extensions", "typing_extensions-4.7.1.tar.gz"),
+            Some("4.7.1".into())
+        );
+        assert_eq!(version_from_filename("black", "blackd-1.0.tar.gz"), None);
+    }
+
+    #[test]
+    fn test_parse_simple_index() {
+        let body = indoc! {r#"
+            <html><body>
+            <a href="https://files.example.com/ruff-0.0.280.tar.gz#sha256=abc">ruff-0.0.280.tar.gz</a><br/>
+            <a href="../../files/ruff-0.0.281-py3-none-any.whl" data-requires-python="&gt;=3.7">ruff-0.0.281-py3-none-any.whl</a><br/>
+            </body></html>
+        "#};
+        assert_eq!(
+            parse_simple_index(body),
+            vec!["ruff-0.0.280.tar.gz", "ruff-0.0.281-py3-none-any.whl"]
+        );
+    }
+
+    #[test]
+    fn test_parse_entry_points() {
+        let contents = indoc! {r#"
+            [console_scripts]
+            black = black:patched_main
+            blackd = blackd:patched_main [d]
+
+            [black.plugins]
+            foo = bar
+        "#};
+        assert_eq!(parse_entry_points(contents), vec!["black", "blackd"]);
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use color_eyre::eyre::{eyre, Result};
+use itertools::Itertools;
+use serde_derive::Deserialize;
+use versions::Versioning;
+
+use crate::cache::CacheManager;
+use crate::cmd::CmdLineRunner;
+use crate::config::{Config, Settings};
+use crate::env::{RTX_EXE, RTX_GO_DOWNLOAD_MIRROR, RTX_GO_RELEASE_INDEX_URL};
+use crate::plugins::core::help;
+use crate::plugins::{Plugin, PluginHelp, PluginName};
+use crate::toolset::{ToolVersion, ToolVersionRequest};
+use crate::ui::progress_report::ProgressReport;
+use crate::{dirs, file, hash, http};
+
+#[derive(Debug)]
+pub struct GoPlugin {
+    pub name: PluginName,
+    remote_version_cache: CacheManager<Vec<String>>,
+    legacy_file_support: bool,
+}
+
+impl GoPlugin {
+    pub fn new(name: PluginName) -> Self {
+        let cache_path = dirs::CACHE.join(&name);
+        let fresh_duration = Some(Duration::from_secs(60 * 60 * 12)); // 12 hours
+        Self {
+            remote_version_cache: CacheManager::new(cache_path.join("remote_versions.msgpack.z"))
+                .with_fresh_duration(fresh_duration)
+                .with_fresh_file(RTX_EXE.clone()),
+            name,
+            legacy_file_support: false,
+        }
+    }
+
+    pub fn with_legacy_file_support(self) -> Self {
+        Self {
+            legacy_file_support: true,
+            ..self
+        }
+    }
+
+    fn fetch_remote_versions(&self) -> Result<Vec<String>> {
+        let http = http::Client::new()?;
+        let body = http.get_text(&*RTX_GO_RELEASE_INDEX_URL)?;
+        let releases: Vec<GoRelease> = serde_json::from_str(&body)?;
+        let versions = releases
+            .into_iter()
+            .filter(|r| {
+                r.files
+                    .iter()
+                    .any(|f| f.kind == "archive" && f.os == platform() && f.arch == arch())
+            })
+            .filter_map(|r| r.version.strip_prefix("go").map(|v| v.to_string()))
+            .unique()
+            .sorted_by_cached_key(|v| Versioning::new(v))
+            .collect();
+        Ok(versions)
+    }
+
+    fn goroot(&self, tv: &ToolVersion) -> PathBuf {
+        tv.install_path().join("go")
+    }
+
+    fn gopath(&self, tv: &ToolVersion) -> PathBuf {
+        tv.install_path().join("packages")
+    }
+
+    fn go_bin(&self, tv: &ToolVersion) -> PathBuf {
+        self.goroot(tv).join("bin/go")
+    }
+
+    fn download(&self, tv: &ToolVersion, pr: &ProgressReport) -> Result<PathBuf> {
+        let http = http::Client::new()?;
+        let filename = format!("go{}.{}-{}.tar.gz", tv.version, platform(), arch());
+        let tarball_url = format!("{}/{}", &*RTX_GO_DOWNLOAD_MIRROR, &filename);
+        let tarball_path = tv.download_path().join(&filename);
+
+        pr.set_message(format!("downloading {}", &tarball_url));
+        http.download_file(&tarball_url, &tarball_path)?;
+
+        pr.set_message(format!("verifying {}", &filename));
+        let checksum = http.get_text(format!("{}.sha256", &tarball_url))?;
+        hash::ensure_checksum_sha256(&tarball_path, checksum.trim())?;
+
+        Ok(tarball_path)
+    }
+
+    fn install(&self, tv: &ToolVersion, pr: &ProgressReport, tarball_path: &Path) -> Result<()> {
+        pr.set_message(format!("installing {}", file::display_path(tarball_path)));
+        file::untar(tarball_path, &tv.install_path())?;
+        Ok(())
+    }
+
+    fn test_go(&self, config: &Config, tv: &ToolVersion, pr: &ProgressReport) -> Result<()> {
+        let mut cmd = CmdLineRunner::new(&config.settings, self.go_bin(tv));
+        cmd.with_pr(pr).arg("version");
+        cmd.execute()
+    }
+}
+
+impl Plugin for GoPlugin {
+    fn name(&self) -> &PluginName {
+        &self.name
+    }
+
+    fn list_remote_versions(&self, _config: &Config) -> Result<Vec<String>> {
+        self.remote_version_cache
+            .get_or_try_init(|| self.fetch_remote_versions())
+            .cloned()
+    }
+
+    fn legacy_filenames(&self, _settings: &Settings) -> Result<Vec<String>> {
+        if self.legacy_file_support {
+            Ok(vec![".go-version".into(), "go.mod".into()])
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    fn parse_legacy_file(&self, path: &Path, _settings: &Settings) -> Result<String> {
+        let contents = fs::read_to_string(path)?;
+        if path.file_name().map_or(false, |f| f == "go.mod") {
+            // a go.mod without a go directive doesn't pin a version
+            return Ok(parse_go_mod(&contents).unwrap_or_default());
+        }
+        Ok(contents.trim().to_string())
+    }
+
+    fn install_version(
+        &self,
+        config: &Config,
+        tv: &ToolVersion,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        if matches!(tv.request, ToolVersionRequest::Ref(..)) {
+            return Err(eyre!("Ref versions not supported for go"));
+        }
+        let tarball_path = self.download(tv, pr)?;
+        self.install(tv, pr, &tarball_path)?;
+        self.test_go(config, tv, pr)?;
+        Ok(())
+    }
+
+    fn list_bin_paths(&self, _config: &Config, tv: &ToolVersion) -> Result<Vec<PathBuf>> {
+        Ok(vec![
+            self.goroot(tv).join("bin"),
+            self.gopath(tv).join("bin"),
+        ])
+    }
+
+    fn exec_env(&self, _config: &Config, tv: &ToolVersion) -> Result<HashMap<String, String>> {
+        let goroot = self.goroot(tv);
+        let gopath = self.gopath(tv);
+        Ok(HashMap::from([
+            ("GOROOT".into(), goroot.to_string_lossy().to_string()),
+            ("GOPATH".into(), gopath.to_string_lossy().to_string()),
+            (
+                "GOBIN".into(),
+                gopath.join("bin").to_string_lossy().to_string(),
+            ),
+        ]))
+    }
+
+    fn help(&self, _config: &Config, _tv: Option<&ToolVersion>) -> Result<Option<PluginHelp>> {
+        Ok(Some(help("go")))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GoRelease {
+    version: String,
+    files: Vec<GoReleaseFile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GoReleaseFile {
+    os: String,
+    arch: String,
+    kind: String,
+}
+
+/// finds the version in the `go` directive of a go.mod file
+/// e.g.: "go 1.20" -> "1.20"
+fn parse_go_mod(contents: &str) -> Option<String> {
+    contents.lines().find_map(|line| {
+        let line = line.split("//").next().unwrap_or_default().trim();
+        match line.split_once(char::is_whitespace) {
+            Some(("go", v)) => Some(v.trim().to_string()),
+            _ => None,
+        }
+    })
+}
+
+fn platform() -> &'static str {
+    match std::env::consts::OS {
+        "macos" => "darwin",
+        os => os,
+    }
+}
+
+fn arch() -> &'static str {
+    match std::env::consts::ARCH {
+        "x86_64" => "amd64",
+        "aarch64" => "arm64",
+        "arm" => "armv6l",
+        arch => arch,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_go_mod() {
+        let go_mod = indoc! {r#"
+            module github.com/jdxcode/example
+
+            go 1.20 // minimum version
+
+            require golang.org/x/mod v0.10.0
+        "#};
+        assert_eq!(parse_go_mod(go_mod), Some("1.20".into()));
+        assert_eq!(parse_go_mod("module example\n"), None);
+        assert_eq!(parse_go_mod("module example\n// go 1.20\n"), None);
+    }
+}
@@ -0,0 +1,316 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use color_eyre::eyre::{eyre, Result};
+use itertools::Itertools;
+use serde_derive::{Deserialize, Serialize};
+use versions::Versioning;
+
+use crate::cache::CacheManager;
+use crate::cmd::CmdLineRunner;
+use crate::config::{Config, Settings};
+use crate::env::{RTX_EXE, RTX_JAVA_METADATA_URL};
+use crate::plugins::core::help;
+use crate::plugins::{Plugin, PluginHelp, PluginName};
+use crate::toolset::{ToolVersion, ToolVersionRequest};
+use crate::ui::progress_report::ProgressReport;
+use crate::{dirs, file, hash, http};
+
+#[derive(Debug)]
+pub struct JavaPlugin {
+    pub name: PluginName,
+    metadata_cache: CacheManager<Vec<JavaMetadata>>,
+}
+
+impl JavaPlugin {
+    pub fn new(name: PluginName) -> Self {
+        let cache_path = dirs::CACHE.join(&name);
+        let fresh_duration = Some(Duration::from_secs(60 * 60 * 12)); // 12 hours
+        Self {
+            metadata_cache: CacheManager::new(cache_path.join("metadata.msgpack.z"))
+                .with_fresh_duration(fresh_duration)
+                .with_fresh_file(RTX_EXE.clone()),
+            name,
+        }
+    }
+
+    fn metadata(&self) -> Result<&Vec<JavaMetadata>> {
+        self.metadata_cache
+            .get_or_try_init(|| self.fetch_metadata())
+    }
+
+    fn fetch_metadata(&self) -> Result<Vec<JavaMetadata>> {
+        let http = http::Client::new()?;
+        let body = http.get_text(metadata_url())?;
+        let metadata: Vec<JavaMetadata> = serde_json::from_str(&body)?;
+        let metadata = metadata
+            .into_iter()
+            .filter(|m| {
+                m.image_type == "jdk"
+                    && m.file_type == "tar.gz"
+                    && m.features.is_empty()
+                    && regex!(r"^[0-9]").is_match(&m.java_version)
+            })
+            .collect();
+        Ok(metadata)
+    }
+
+    fn find_metadata(&self, tv: &ToolVersion) -> Result<JavaMetadata> {
+        self.metadata()?
+            .iter()
+            .find(|m| version_names(m).contains(&tv.version))
+            .cloned()
+            .ok_or_else(|| eyre!("no java distribution found for {}", tv))
+    }
+
+    fn download(
+        &self,
+        tv: &ToolVersion,
+        metadata: &JavaMetadata,
+        pr: &ProgressReport,
+    ) -> Result<PathBuf> {
+        let http = http::Client::new()?;
+        let filename = metadata.url.rsplit('/').next().unwrap_or("jdk.tar.gz");
+        let tarball_path = tv.download_path().join(filename);
+
+        pr.set_message(format!("downloading {}", &metadata.url));
+        http.download_file(&metadata.url, &tarball_path)?;
+
+        if let Some(checksum) = &metadata.sha256 {
+            pr.set_message(format!("verifying {}", filename));
+            hash::ensure_checksum_sha256(&tarball_path, checksum)?;
+        }
+        Ok(tarball_path)
+    }
+
+    fn install(&self, tv: &ToolVersion, pr: &ProgressReport, tarball_path: &Path) -> Result<()> {
+        pr.set_message(format!("installing {}", file::display_path(tarball_path)));
+        let extract_path = tv.download_path().join("extract");
+        file::remove_all(&extract_path)?;
+        file::untar(tarball_path, &extract_path)?;
+        // archives contain a single top-level directory, e.g.: jdk-17.0.8+7
+        let top = match file::dir_subdirs(&extract_path)?.as_slice() {
+            [dir] => extract_path.join(dir),
+            _ => extract_path,
+        };
+        file::remove_all(tv.install_path())?;
+        fs::rename(top, tv.install_path())?;
+        Ok(())
+    }
+
+    fn java_home(&self, tv: &ToolVersion) -> PathBuf {
+        // macOS archives are bundles with the jdk in Contents/Home
+        let bundle_home = tv.install_path().join("Contents/Home");
+        match bundle_home.exists() {
+            true => bundle_home,
+            false => tv.install_path(),
+        }
+    }
+
+    fn test_java(&self, config: &Config, tv: &ToolVersion, pr: &ProgressReport) -> Result<()> {
+        let mut cmd = CmdLineRunner::new(&config.settings, self.java_home(tv).join("bin/java"));
+        cmd.with_pr(pr).arg("-version");
+        cmd.execute()
+    }
+}
+
+impl Plugin for JavaPlugin {
+    fn name(&self) -> &PluginName {
+        &self.name
+    }
+
+    fn list_remote_versions(&self, _config: &Config) -> Result<Vec<String>> {
+        let versions = self
+            .metadata()?
+            .iter()
+            .flat_map(version_names)
+            .unique()
+            .sorted_by_cached_key(|v| version_sort_key(v))
+            .collect();
+        Ok(versions)
+    }
+
+    fn legacy_filenames(&self, _settings: &Settings) -> Result<Vec<String>> {
+        Ok(vec![".java-version".into(), ".sdkmanrc".into()])
+    }
+
+    fn parse_legacy_file(&self, path: &Path, _settings: &Settings) -> Result<String> {
+        let contents = fs::read_to_string(path)?;
+        if path.file_name().map_or(false, |f| f == ".sdkmanrc") {
+            // .sdkmanrc can pin only other sdks like maven or gradle, those don't set a version
+            return Ok(parse_sdkmanrc(&contents).unwrap_or_default());
+        }
+        Ok(contents.trim().to_string())
+    }
+
+    fn install_version(
+        &self,
+        config: &Config,
+        tv: &ToolVersion,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        if matches!(tv.request, ToolVersionRequest::Ref(..)) {
+            return Err(eyre!("Ref versions not supported for java"));
+        }
+        let metadata = self.find_metadata(tv)?;
+        let tarball_path = self.download(tv, &metadata, pr)?;
+        self.install(tv, pr, &tarball_path)?;
+        self.test_java(config, tv, pr)?;
+        Ok(())
+    }
+
+    fn list_bin_paths(&self, _config: &Config, tv: &ToolVersion) -> Result<Vec<PathBuf>> {
+        Ok(vec![self.java_home(tv).join("bin")])
+    }
+
+    fn exec_env(&self, _config: &Config, tv: &ToolVersion) -> Result<HashMap<String, String>> {
+        Ok(HashMap::from([(
+            "JAVA_HOME".into(),
+            self.java_home(tv).to_string_lossy().to_string(),
+        )]))
+    }
+
+    fn help(&self, _config: &Config, _tv: Option<&ToolVersion>) -> Result<Option<PluginHelp>> {
+        Ok(Some(help("java")))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct JavaMetadata {
+    vendor: String,
+    java_version: String,
+    url: String,
+    file_type: String,
+    image_type: String,
+    #[serde(default)]
+    features: Vec<String>,
+    sha256: Option<String>,
+}
+
+/// the names a distribution can be installed as, e.g.: "temurin-17.0.8"
+/// openjdk is the default distribution so it is also available without a prefix
+fn version_names(m: &JavaMetadata) -> Vec<String> {
+    let version = m.java_version.split('+').next().unwrap_or_default();
+    let mut names = vec![format!("{}-{}", m.vendor, version)];
+    if m.vendor == "openjdk" {
+        names.push(version.to_string());
+    }
+    names
+}
+
+/// groups versions by distribution then sorts each distribution by version
+fn version_sort_key(v: &str) -> (String, Option<Versioning>) {
+    match regex!(r"^([a-z][a-z0-9_-]*?)-([0-9].*)$").captures(v) {
+        Some(c) => (c[1].to_string(), Versioning::new(&c[2])),
+        None => (String::new(), Versioning::new(v)),
+    }
+}
+
+/// converts sdkman identifiers like "java=17.0.8-tem" to "temurin-17.0.8"
+fn parse_sdkmanrc(contents: &str) -> Option<String> {
+    contents.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        if key.trim() != "java" {
+            return None;
+        }
+        let value = value.split('#').next().unwrap_or_default().trim();
+        let (version, vendor) = value.rsplit_once('-')?;
+        let vendor = match vendor {
+            "amzn" => "corretto",
+            "graal" | "graalce" => "graalvm-community",
+            "librca" => "liberica",
+            "ms" => "microsoft",
+            "open" => "openjdk",
+            "sapmchn" => "sapmachine",
+            "sem" => "semeru",
+            "tem" => "temurin",
+            vendor => vendor,
+        };
+        Some(format!("{vendor}-{version}"))
+    })
+}
+
+/// the metadata url can be a url or a local path with {os} and {arch} placeholders
+fn metadata_url() -> String {
+    let url = RTX_JAVA_METADATA_URL
+        .replace("{os}", os())
+        .replace("{arch}", arch());
+    match url.contains("://") {
+        true => url,
+        false => format!("file://{}", file::replace_path(url).display()),
+    }
+}
+
+fn os() -> &'static str {
+    match std::env::consts::OS {
+        "macos" => "macosx",
+        os => os,
+    }
+}
+
+fn arch() -> &'static str {
+    match std::env::consts::ARCH {
+        "arm" => "arm32-vfp-hflt",
+        arch => arch,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_names() {
+        let metadata: Vec<JavaMetadata> = serde_json::from_str(
+            r#"[
+                {"vendor": "temurin", "java_version": "17.0.8+7", "url": "https://example.com/a.tar.gz",
+                 "file_type": "tar.gz", "image_type": "jdk", "features": [], "sha256": null},
+                {"vendor": "openjdk", "java_version": "20.0.2", "url": "https://example.com/b.tar.gz",
+                 "file_type": "tar.gz", "image_type": "jdk"}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(version_names(&metadata[0]), vec!["temurin-17.0.8"]);
+        assert_eq!(
+            version_names(&metadata[1]),
+            vec!["openjdk-20.0.2", "20.0.2"]
+        );
+    }
+
+    #[test]
+    fn test_version_sort_key() {
+        let versions = [
+            "zulu-21.0.1",
+            "temurin-17.0.10",
+            "graalvm-community-21.0.1",
+            "17.0.2",
+            "temurin-17.0.9",
+        ];
+        let sorted = versions
+            .into_iter()
+            .sorted_by_cached_key(|v| version_sort_key(v))
+            .collect_vec();
+        assert_eq!(
+            sorted,
+            vec![
+                "17.0.2",
+                "graalvm-community-21.0.1",
+                "temurin-17.0.9",
+                "temurin-17.0.10",
+                "zulu-21.0.1"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_sdkmanrc() {
+        assert_eq!(
+            parse_sdkmanrc("# comment\njava=17.0.8-tem\n"),
+            Some("temurin-17.0.8".into())
+        );
+        assert_eq!(parse_sdkmanrc("java=21-zulu"), Some("zulu-21".into()));
+        assert_eq!(parse_sdkmanrc("maven=3.9.4"), None);
+    }
+}
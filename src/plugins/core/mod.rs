@@ -5,12 +5,18 @@ use once_cell::sync::Lazy;
 
 pub use python::PythonPlugin;
 
+use crate::plugins::core::go::GoPlugin;
+use crate::plugins::core::java::JavaPlugin;
 use crate::plugins::core::node::NodePlugin;
-use crate::plugins::{Plugin, PluginName};
+use crate::plugins::core::ruby::RubyPlugin;
+use crate::plugins::{Plugin, PluginHelp, PluginName};
 use crate::tool::Tool;
 
+mod go;
+mod java;
 mod node;
 mod python;
+mod ruby;
 
 type ToolMap = BTreeMap<PluginName, Arc<Tool>>;
 
@@ -18,11 +24,18 @@ pub static CORE_PLUGINS: Lazy<ToolMap> = Lazy::new(|| {
     build_core_plugins(vec![
         Box::new(NodePlugin::new("node".to_string()).with_legacy_file_support()),
         Box::new(NodePlugin::new("nodejs".to_string())),
+        Box::new(GoPlugin::new("go".to_string()).with_legacy_file_support()),
+        Box::new(GoPlugin::new("golang".to_string())),
+        Box::new(JavaPlugin::new("java".to_string())),
     ])
 });
 
-pub static EXPERIMENTAL_CORE_PLUGINS: Lazy<ToolMap> =
-    Lazy::new(|| build_core_plugins(vec![Box::new(PythonPlugin::new("python".to_string()))]));
+pub static EXPERIMENTAL_CORE_PLUGINS: Lazy<ToolMap> = Lazy::new(|| {
+    build_core_plugins(vec![
+        Box::new(PythonPlugin::new("python".to_string())),
+        Box::new(RubyPlugin::new("ruby".to_string())),
+    ])
+});
 
 fn build_core_plugins(tools: Vec<Box<dyn Plugin>>) -> ToolMap {
     ToolMap::from_iter(tools.into_iter().map(|plugin| {
@@ -32,3 +45,13 @@ fn build_core_plugins(tools: Vec<Box<dyn Plugin>>) -> ToolMap {
         )
     }))
 }
+
+/// `rtx plugins help` for core plugins links to their documentation in docs/<name>.md
+fn help(name: &str) -> PluginHelp {
+    PluginHelp {
+        overview: format!(
+            "{name} is a core plugin, see https://github.com/jdxcode/rtx/blob/main/docs/{name}.md"
+        ),
+        ..Default::default()
+    }
+}
@@ -5,34 +5,46 @@ use std::path::PathBuf;
 use std::process::exit;
 use std::time::Duration;
 
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
+use itertools::Itertools;
+use serde::Deserializer;
+use serde_derive::{Deserialize, Serialize};
+use versions::Versioning;
 
 use crate::cache::CacheManager;
 use crate::cmd::CmdLineRunner;
 use crate::config::{Config, Settings};
-use crate::env::{RTX_EXE, RTX_NODE_CONCURRENCY, RTX_NODE_FORCE_COMPILE, RTX_NODE_VERBOSE_INSTALL};
+use crate::env::{
+    PREFER_STALE, RTX_EXE, RTX_NODE_CONCURRENCY, RTX_NODE_FORCE_COMPILE, RTX_NODE_MIRROR_URL,
+    RTX_NODE_VERBOSE_INSTALL,
+};
 use crate::file::create_dir_all;
 use crate::git::Git;
 use crate::lock_file::LockFile;
-use crate::plugins::{Plugin, PluginName};
+use crate::plugins::core::help;
+use crate::plugins::{Plugin, PluginHelp, PluginName};
 use crate::toolset::{ToolVersion, ToolVersionRequest};
 use crate::ui::progress_report::ProgressReport;
-use crate::{cmd, dirs, env};
+use crate::{cmd, dirs, env, file, hash, http};
 
 #[derive(Debug)]
 pub struct NodePlugin {
     pub name: PluginName,
     cache_path: PathBuf,
-    remote_version_cache: CacheManager<Vec<String>>,
+    remote_index_cache: CacheManager<Vec<NodeVersion>>,
     legacy_file_support: bool,
 }
 
 impl NodePlugin {
     pub fn new(name: PluginName) -> Self {
         let cache_path = dirs::CACHE.join(&name);
-        let fresh_duration = Some(Duration::from_secs(60 * 60 * 12)); // 12 hours
+        let fresh_duration = if *PREFER_STALE {
+            None
+        } else {
+            Some(Duration::from_secs(60 * 60 * 12)) // 12 hours
+        };
         Self {
-            remote_version_cache: CacheManager::new(cache_path.join("remote_versions.msgpack.z"))
+            remote_index_cache: CacheManager::new(cache_path.join("remote_index.msgpack.z"))
                 .with_fresh_duration(fresh_duration)
                 .with_fresh_file(RTX_EXE.clone()),
             name,
@@ -94,15 +106,99 @@ impl NodePlugin {
         Ok(())
     }
 
-    fn fetch_remote_versions(&self) -> Result<Vec<String>> {
-        self.install_or_update_node_build()?;
-        let output = cmd!(self.node_build_bin(), "--definitions").read()?;
-        let versions = output
-            .split('\n')
-            .filter(|s| regex!(r"^[0-9].+$").is_match(s))
-            .map(|s| s.to_string())
+    fn remote_index(&self) -> Result<&Vec<NodeVersion>> {
+        self.remote_index_cache
+            .get_or_try_init(|| self.fetch_remote_index())
+    }
+
+    fn fetch_remote_index(&self) -> Result<Vec<NodeVersion>> {
+        let http = http::Client::new()?;
+        let body = http.get_text(mirror_url("index.json"))?;
+        let index: Vec<NodeVersion> = serde_json::from_str(&body)?;
+        let index = index
+            .into_iter()
+            .filter_map(|v| {
+                let version = v.version.strip_prefix('v')?.to_string();
+                Some(NodeVersion { version, ..v })
+            })
+            .unique_by(|v| v.version.clone())
+            .sorted_by_cached_key(|v| Versioning::new(&v.version))
             .collect();
-        Ok(versions)
+        Ok(index)
+    }
+
+    /// falls back to the last fetched index when offline, alias lookups shouldn't fail for that
+    fn lts_aliases(&self) -> BTreeMap<String, String> {
+        match self.remote_index() {
+            Ok(index) => lts_aliases(index),
+            Err(err) => {
+                debug!("failed to fetch node index: {:#}", err);
+                self.remote_index_cache
+                    .get_stale()
+                    .map(|index| lts_aliases(&index))
+                    .unwrap_or_default()
+            }
+        }
+    }
+
+    fn should_compile(&self, tv: &ToolVersion) -> bool {
+        matches!(&tv.request, ToolVersionRequest::Ref { .. })
+            || *RTX_NODE_FORCE_COMPILE
+            || !regex!(r"^[0-9]").is_match(&tv.version)
+    }
+
+    fn install_precompiled(&self, tv: &ToolVersion, pr: &ProgressReport) -> Result<()> {
+        let http = http::Client::new()?;
+        let slug = format!("node-v{}-{}-{}", tv.version, platform(), arch());
+        let filename = format!("{slug}.tar.gz");
+        let tarball_url = mirror_url(&format!("v{}/{}", tv.version, filename));
+        let tarball_path = tv.download_path().join(&filename);
+
+        pr.set_message(format!("downloading {}", &tarball_url));
+        http.download_file(&tarball_url, &tarball_path)?;
+
+        pr.set_message(format!("verifying {}", &filename));
+        let shasums = http.get_text(mirror_url(&format!("v{}/SHASUMS256.txt", tv.version)))?;
+        let checksum = find_checksum(&shasums, &filename)
+            .ok_or_else(|| eyre!("no checksum found for {} in SHASUMS256.txt", filename))?;
+        hash::ensure_checksum_sha256(&tarball_path, checksum)?;
+
+        pr.set_message(format!("installing {}", file::display_path(&tarball_path)));
+        let extract_path = tv.download_path().join("extract");
+        file::remove_all(&extract_path)?;
+        file::untar(&tarball_path, &extract_path)?;
+        file::remove_all(tv.install_path())?;
+        fs::rename(extract_path.join(slug), tv.install_path())?;
+        Ok(())
+    }
+
+    fn install_compiled(
+        &self,
+        config: &Config,
+        tv: &ToolVersion,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        self.install_node_build()?;
+        pr.set_message("running node-build");
+        let mut cmd = CmdLineRunner::new(&config.settings, self.node_build_bin());
+        cmd.with_pr(pr).arg(tv.version.as_str());
+        if matches!(&tv.request, ToolVersionRequest::Ref { .. }) || *RTX_NODE_FORCE_COMPILE {
+            let make_opts = String::from(" -j") + &RTX_NODE_CONCURRENCY.to_string();
+            cmd.env(
+                "MAKE_OPTS",
+                env::var("MAKE_OPTS").unwrap_or_default() + &make_opts,
+            );
+            cmd.env(
+                "NODE_MAKE_OPTS",
+                env::var("NODE_MAKE_OPTS").unwrap_or_default() + &make_opts,
+            );
+            cmd.arg("--compile");
+        }
+        if *RTX_NODE_VERBOSE_INSTALL {
+            cmd.arg("--verbose");
+        }
+        cmd.arg(tv.install_path());
+        cmd.execute()
     }
 
     fn node_path(&self, tv: &ToolVersion) -> PathBuf {
@@ -163,28 +259,24 @@ impl Plugin for NodePlugin {
         &self.name
     }
 
-    fn list_remote_versions(&self, _settings: &Settings) -> Result<Vec<String>> {
-        self.remote_version_cache
-            .get_or_try_init(|| self.fetch_remote_versions())
-            .cloned()
+    fn list_remote_versions(&self, _config: &Config) -> Result<Vec<String>> {
+        let versions = self
+            .remote_index()?
+            .iter()
+            .map(|v| v.version.clone())
+            .collect();
+        Ok(versions)
     }
 
     fn get_aliases(&self, _settings: &Settings) -> Result<BTreeMap<String, String>> {
-        let aliases = [
-            ("lts/argon", "4"),
-            ("lts/boron", "6"),
-            ("lts/carbon", "8"),
-            ("lts/dubnium", "10"),
-            ("lts/erbium", "12"),
-            ("lts/fermium", "14"),
-            ("lts/gallium", "16"),
-            ("lts/hydrogen", "18"),
-            ("lts", "18"),
-        ]
-        .into_iter()
-        .map(|(k, v)| (k.to_string(), v.to_string()))
-        .collect();
-        Ok(aliases)
+        Ok(self.lts_aliases())
+    }
+
+    fn get_alias(&self, _settings: &Settings, alias: &str) -> Result<Option<String>> {
+        if !alias.starts_with("lts") {
+            return Ok(None);
+        }
+        Ok(self.lts_aliases().remove(alias))
     }
 
     fn legacy_filenames(&self, _settings: &Settings) -> Result<Vec<String>> {
@@ -216,31 +308,129 @@ impl Plugin for NodePlugin {
         tv: &ToolVersion,
         pr: &ProgressReport,
     ) -> Result<()> {
-        self.install_node_build()?;
-        pr.set_message("running node-build");
-        let mut cmd = CmdLineRunner::new(&config.settings, self.node_build_bin());
-        cmd.with_pr(pr).arg(tv.version.as_str());
-        if matches!(&tv.request, ToolVersionRequest::Ref { .. }) || *RTX_NODE_FORCE_COMPILE {
-            let make_opts = String::from(" -j") + &RTX_NODE_CONCURRENCY.to_string();
-            cmd.env(
-                "MAKE_OPTS",
-                env::var("MAKE_OPTS").unwrap_or_default() + &make_opts,
-            );
-            cmd.env(
-                "NODE_MAKE_OPTS",
-                env::var("NODE_MAKE_OPTS").unwrap_or_default() + &make_opts,
-            );
-            cmd.arg("--compile");
-        }
-        if *RTX_NODE_VERBOSE_INSTALL {
-            cmd.arg("--verbose");
+        if self.should_compile(tv) {
+            self.install_compiled(config, tv, pr)?;
+        } else {
+            self.install_precompiled(tv, pr)?;
         }
-        cmd.arg(tv.install_path());
-        cmd.execute()?;
         self.test_node(config, tv, pr)?;
         self.install_npm_shim(tv)?;
         self.test_npm(config, tv, pr)?;
         self.install_default_packages(&config.settings, tv, pr)?;
         Ok(())
     }
+
+    fn help(&self, _config: &Config, _tv: Option<&ToolVersion>) -> Result<Option<PluginHelp>> {
+        Ok(Some(help("node")))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NodeVersion {
+    version: String,
+    #[serde(default, deserialize_with = "deserialize_lts")]
+    lts: Option<String>,
+}
+
+/// the index has `"lts": false` for non-lts versions and `"lts": "Hydrogen"` for lts versions
+fn deserialize_lts<'de, D>(deserializer: D) -> std::result::Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let lts: Option<serde_json::Value> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(lts.and_then(|lts| lts.as_str().map(|s| s.to_string())))
+}
+
+/// builds aliases like "lts/hydrogen" -> "18" from the codenames in the index
+/// "lts" points to the newest lts line
+fn lts_aliases(index: &[NodeVersion]) -> BTreeMap<String, String> {
+    let mut aliases = BTreeMap::new();
+    for v in index {
+        if let Some(codename) = &v.lts {
+            let major = v.version.split('.').next().unwrap_or_default().to_string();
+            aliases.insert(format!("lts/{}", codename.to_lowercase()), major.clone());
+            aliases.insert("lts".into(), major);
+        }
+    }
+    aliases
+}
+
+fn mirror_url(path: &str) -> String {
+    format!("{}/{}", RTX_NODE_MIRROR_URL.trim_end_matches('/'), path)
+}
+
+/// finds the checksum for filename in the contents of a SHASUMS256.txt file
+fn find_checksum<'a>(shasums: &'a str, filename: &str) -> Option<&'a str> {
+    shasums.lines().find_map(|line| {
+        let (checksum, f) = line.split_once(char::is_whitespace)?;
+        (f.trim() == filename).then_some(checksum)
+    })
+}
+
+fn platform() -> &'static str {
+    match std::env::consts::OS {
+        "macos" => "darwin",
+        os => os,
+    }
+}
+
+fn arch() -> &'static str {
+    match std::env::consts::ARCH {
+        "x86_64" => "x64",
+        "aarch64" => "arm64",
+        "arm" => "armv7l",
+        arch => arch,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_find_checksum() {
+        let shasums = indoc! {r#"
+            0f2b8c4e1e6ab1d5e6e8e2f8e5a0b8d0  node-v20.5.0-darwin-arm64.tar.gz
+            a4e4e9b1a0ed0e0b7ea73e4a3ad8c6a2  node-v20.5.0-linux-x64.tar.gz
+        "#};
+        assert_eq!(
+            find_checksum(shasums, "node-v20.5.0-linux-x64.tar.gz"),
+            Some("a4e4e9b1a0ed0e0b7ea73e4a3ad8c6a2")
+        );
+        assert_eq!(
+            find_checksum(shasums, "node-v20.5.0-linux-x64.tar.xz"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_lts_aliases() {
+        let index: Vec<NodeVersion> = serde_json::from_str(
+            r#"[
+                {"version": "16.20.1", "lts": "Gallium"},
+                {"version": "18.17.0", "lts": "Hydrogen"},
+                {"version": "20.4.0", "lts": false},
+                {"version": "20.9.0", "lts": "Iron"}
+            ]"#,
+        )
+        .unwrap();
+        let aliases = lts_aliases(&index);
+        assert_eq!(aliases["lts/gallium"], "16");
+        assert_eq!(aliases["lts/hydrogen"], "18");
+        assert_eq!(aliases["lts/iron"], "20");
+        assert_eq!(aliases["lts"], "20");
+    }
+
+    #[test]
+    fn test_get_alias() {
+        let plugin = NodePlugin::new("node".into());
+        let settings = Settings::default();
+        assert_eq!(
+            plugin.get_alias(&settings, "lts/hydrogen").unwrap(),
+            Some("18".into())
+        );
+        assert_eq!(plugin.get_alias(&settings, "20").unwrap(), None);
+    }
 }
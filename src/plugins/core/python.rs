@@ -1,25 +1,31 @@
 use std::collections::HashMap;
+use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use color_eyre::eyre::{eyre, Result};
+use itertools::Itertools;
+use serde_derive::{Deserialize, Serialize};
+use versions::Versioning;
 
 use crate::cache::CacheManager;
 use crate::cmd::CmdLineRunner;
 use crate::config::{Config, Settings};
-use crate::env::RTX_EXE;
+use crate::env::{RTX_EXE, RTX_PYTHON_PRECOMPILED_MANIFEST, RTX_PYTHON_PRECOMPILED_TARGET};
 use crate::file::create_dir_all;
 use crate::git::Git;
-use crate::plugins::{Plugin, PluginName};
+use crate::plugins::core::help;
+use crate::plugins::{Plugin, PluginHelp, PluginName};
 use crate::toolset::{ToolVersion, ToolVersionRequest};
 use crate::ui::progress_report::ProgressReport;
-use crate::{cmd, dirs, env, file, http};
+use crate::{cmd, dirs, env, file, hash, http};
 
 #[derive(Debug)]
 pub struct PythonPlugin {
     pub name: PluginName,
     cache_path: PathBuf,
     remote_version_cache: CacheManager<Vec<String>>,
+    precompiled_cache: CacheManager<Vec<PrecompiledPython>>,
 }
 
 impl PythonPlugin {
@@ -30,6 +36,9 @@ impl PythonPlugin {
             remote_version_cache: CacheManager::new(cache_path.join("remote_versions.msgpack.z"))
                 .with_fresh_duration(fresh_duration)
                 .with_fresh_file(RTX_EXE.clone()),
+            precompiled_cache: CacheManager::new(cache_path.join("precompiled.msgpack.z"))
+                .with_fresh_duration(fresh_duration)
+                .with_fresh_file(RTX_EXE.clone()),
             name,
             cache_path,
         }
@@ -79,6 +88,98 @@ impl PythonPlugin {
         Ok(output.split('\n').map(|s| s.to_string()).collect())
     }
 
+    fn precompiled_versions(&self) -> Result<&Vec<PrecompiledPython>> {
+        self.precompiled_cache
+            .get_or_try_init(|| self.fetch_precompiled_versions())
+    }
+
+    fn fetch_precompiled_versions(&self) -> Result<Vec<PrecompiledPython>> {
+        let http = http::Client::new()?;
+        let body = http.get_text(manifest_url())?;
+        let release: PrecompiledRelease = serde_json::from_str(&body)?;
+        Ok(parse_precompiled_release(&release, &precompiled_target()))
+    }
+
+    fn find_precompiled(&self, tv: &ToolVersion) -> Result<Option<PrecompiledPython>> {
+        Ok(self
+            .precompiled_versions()?
+            .iter()
+            .find(|p| p.version == tv.version)
+            .cloned())
+    }
+
+    fn install_precompiled(
+        &self,
+        tv: &ToolVersion,
+        precompiled: &PrecompiledPython,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        let http = http::Client::new()?;
+        let filename = &precompiled.filename;
+        let tarball_path = tv.download_path().join(filename);
+
+        pr.set_message(format!("downloading {}", &precompiled.url));
+        http.download_file(&precompiled.url, &tarball_path)?;
+
+        if let Some(checksum_url) = &precompiled.checksum_url {
+            pr.set_message(format!("verifying {}", filename));
+            let checksums = http.get_text(checksum_url)?;
+            let checksum = parse_checksum(&checksums, filename)
+                .ok_or_else(|| eyre!("no checksum found for {}", filename))?;
+            hash::ensure_checksum_sha256(&tarball_path, checksum)?;
+        }
+
+        pr.set_message(format!("installing {}", file::display_path(&tarball_path)));
+        let extract_path = tv.download_path().join("extract");
+        file::remove_all(&extract_path)?;
+        file::untar(&tarball_path, &extract_path)?;
+        file::remove_all(tv.install_path())?;
+        fs::rename(extract_path.join("python"), tv.install_path())?;
+
+        // the install_only archives only ship python3 and pip3
+        for (link, target) in [("python", "python3"), ("pip", "pip3")] {
+            let link = tv.install_path().join("bin").join(link);
+            if !link.exists() {
+                file::make_symlink(Path::new(target), &link)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn install_compiled(
+        &self,
+        config: &Config,
+        tv: &ToolVersion,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        self.install_python_build()?;
+        pr.set_message("running python-build");
+        let mut cmd = CmdLineRunner::new(&config.settings, self.python_build_bin());
+        cmd.with_pr(pr)
+            .arg(tv.version.as_str())
+            .arg(tv.install_path());
+        if let Some(patch_url) = &*env::RTX_PYTHON_PATCH_URL {
+            pr.set_message(format!("with patch file from: {patch_url}"));
+            cmd.arg("--patch");
+            let http = http::Client::new()?;
+            let patch = http.get(patch_url).send()?.text()?;
+            cmd.stdin_string(patch);
+        }
+        if let Some(patches_dir) = &*env::RTX_PYTHON_PATCHES_DIRECTORY {
+            dbg!(patches_dir);
+            let patch_file = patches_dir.join(format!("{}.patch", tv.version));
+            if patch_file.exists() {
+                pr.set_message(format!("with patch file: {}", patch_file.display()));
+                cmd.arg("--patch");
+                let contents = std::fs::read_to_string(&patch_file)?;
+                cmd.stdin_string(contents);
+            } else {
+                pr.warn(format!("patch file not found: {}", patch_file.display()));
+            }
+        }
+        cmd.execute()
+    }
+
     fn python_path(&self, tv: &ToolVersion) -> PathBuf {
         tv.install_path().join("bin/python")
     }
@@ -155,7 +256,15 @@ impl Plugin for PythonPlugin {
         &self.name
     }
 
-    fn list_remote_versions(&self, _settings: &Settings) -> Result<Vec<String>> {
+    fn list_remote_versions(&self, config: &Config) -> Result<Vec<String>> {
+        if config.settings.python_precompiled {
+            match self.precompiled_versions() {
+                Ok(versions) => return Ok(versions.iter().map(|p| p.version.clone()).collect()),
+                // the versions python-build can compile are listed instead, like install falls
+                // back to compiling
+                Err(err) => warn!("failed to fetch precompiled pythons: {:#}", err),
+            }
+        }
         self.remote_version_cache
             .get_or_try_init(|| self.fetch_remote_versions())
             .cloned()
@@ -171,35 +280,24 @@ impl Plugin for PythonPlugin {
         tv: &ToolVersion,
         pr: &ProgressReport,
     ) -> Result<()> {
-        self.install_python_build()?;
         if matches!(tv.request, ToolVersionRequest::Ref(..)) {
             return Err(eyre!("Ref versions not supported for python"));
         }
-        pr.set_message("running python-build");
-        let mut cmd = CmdLineRunner::new(&config.settings, self.python_build_bin());
-        cmd.with_pr(pr)
-            .arg(tv.version.as_str())
-            .arg(tv.install_path());
-        if let Some(patch_url) = &*env::RTX_PYTHON_PATCH_URL {
-            pr.set_message(format!("with patch file from: {patch_url}"));
-            cmd.arg("--patch");
-            let http = http::Client::new()?;
-            let patch = http.get(patch_url).send()?.text()?;
-            cmd.stdin_string(patch);
-        }
-        if let Some(patches_dir) = &*env::RTX_PYTHON_PATCHES_DIRECTORY {
-            dbg!(patches_dir);
-            let patch_file = patches_dir.join(format!("{}.patch", tv.version));
-            if patch_file.exists() {
-                pr.set_message(format!("with patch file: {}", patch_file.display()));
-                cmd.arg("--patch");
-                let contents = std::fs::read_to_string(&patch_file)?;
-                cmd.stdin_string(contents);
-            } else {
-                pr.warn(format!("patch file not found: {}", patch_file.display()));
+        if config.settings.python_precompiled {
+            match self.find_precompiled(tv) {
+                Ok(Some(precompiled)) => self.install_precompiled(tv, &precompiled, pr)?,
+                Ok(None) => {
+                    pr.warn(format!("no precompiled python found for {}, compiling", tv));
+                    self.install_compiled(config, tv, pr)?;
+                }
+                Err(err) => {
+                    pr.warn(format!("failed to fetch precompiled pythons: {:#}", err));
+                    self.install_compiled(config, tv, pr)?;
+                }
             }
+        } else {
+            self.install_compiled(config, tv, pr)?;
         }
-        cmd.execute()?;
         self.test_python(&config, tv)?;
         self.get_virtualenv(config, tv, Some(pr))?;
         self.install_default_packages(&config.settings, tv, pr)?;
@@ -225,4 +323,147 @@ impl Plugin for PythonPlugin {
             Ok(HashMap::new())
         }
     }
+
+    fn help(&self, _config: &Config, _tv: Option<&ToolVersion>) -> Result<Option<PluginHelp>> {
+        Ok(Some(help("python")))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct PrecompiledRelease {
+    assets: Vec<PrecompiledAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PrecompiledAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PrecompiledPython {
+    version: String,
+    /// asset name from the manifest, the url has it percent-encoded
+    filename: String,
+    url: String,
+    checksum_url: Option<String>,
+}
+
+/// the manifest can be a url or a path to a local file
+fn manifest_url() -> String {
+    let manifest = &*RTX_PYTHON_PRECOMPILED_MANIFEST;
+    match manifest.contains("://") {
+        true => manifest.to_string(),
+        false => format!("file://{}", file::replace_path(manifest).display()),
+    }
+}
+
+fn precompiled_target() -> String {
+    if let Some(target) = &*RTX_PYTHON_PRECOMPILED_TARGET {
+        return target.to_string();
+    }
+    let os = match std::env::consts::OS {
+        "macos" => "apple-darwin",
+        _ => "unknown-linux-gnu",
+    };
+    format!("{}-{}", std::env::consts::ARCH, os)
+}
+
+/// finds the "install_only" archives for target in a python-build-standalone release
+fn parse_precompiled_release(release: &PrecompiledRelease, target: &str) -> Vec<PrecompiledPython> {
+    let re = regex!(r"^cpython-(\d+\.\d+\.\d+)\+\d+-(.+)-install_only\.tar\.gz$");
+    let sha256sums = release
+        .assets
+        .iter()
+        .find(|a| a.name == "SHA256SUMS")
+        .map(|a| a.browser_download_url.clone());
+    release
+        .assets
+        .iter()
+        .filter_map(|asset| {
+            let captures = re.captures(&asset.name)?;
+            if &captures[2] != target {
+                return None;
+            }
+            let checksum_name = format!("{}.sha256", asset.name);
+            let checksum_url = release
+                .assets
+                .iter()
+                .find(|a| a.name == checksum_name)
+                .map(|a| a.browser_download_url.clone())
+                .or_else(|| sha256sums.clone());
+            Some(PrecompiledPython {
+                version: captures[1].to_string(),
+                filename: asset.name.clone(),
+                url: asset.browser_download_url.clone(),
+                checksum_url,
+            })
+        })
+        .unique_by(|p| p.version.clone())
+        .sorted_by_cached_key(|p| Versioning::new(&p.version))
+        .collect()
+}
+
+/// reads a checksum from either a SHA256SUMS file or a file containing only the checksum
+fn parse_checksum<'a>(checksums: &'a str, filename: &str) -> Option<&'a str> {
+    checksums.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        match (parts.next(), parts.next()) {
+            (Some(checksum), None) => Some(checksum),
+            (Some(checksum), Some(f)) if f.trim_start_matches('*') == filename => Some(checksum),
+            _ => None,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_precompiled_release() {
+        let release: PrecompiledRelease = serde_json::from_str(indoc! {r#"
+            {"assets": [
+              {"name": "cpython-3.11.4+20230726-x86_64-unknown-linux-gnu-install_only.tar.gz",
+               "browser_download_url": "https://example.com/a/cpython-3.11.4%2B20230726-x86_64-unknown-linux-gnu-install_only.tar.gz"},
+              {"name": "cpython-3.11.4+20230726-x86_64-unknown-linux-gnu-install_only.tar.gz.sha256",
+               "browser_download_url": "https://example.com/a/cpython-3.11.4.tar.gz.sha256"},
+              {"name": "cpython-3.10.12+20230726-x86_64-unknown-linux-gnu-install_only.tar.gz",
+               "browser_download_url": "https://example.com/a/cpython-3.10.12.tar.gz"},
+              {"name": "cpython-3.10.12+20230726-aarch64-apple-darwin-install_only.tar.gz",
+               "browser_download_url": "https://example.com/a/cpython-3.10.12-darwin.tar.gz"},
+              {"name": "cpython-3.10.12+20230726-x86_64-unknown-linux-gnu-pgo-full.tar.zst",
+               "browser_download_url": "https://example.com/a/cpython-3.10.12-full.tar.zst"}
+            ]}
+        "#})
+        .unwrap();
+        let versions = parse_precompiled_release(&release, "x86_64-unknown-linux-gnu");
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].version, "3.10.12");
+        assert_eq!(versions[0].checksum_url, None);
+        assert_eq!(versions[1].version, "3.11.4");
+        assert_eq!(
+            versions[1].filename,
+            "cpython-3.11.4+20230726-x86_64-unknown-linux-gnu-install_only.tar.gz"
+        );
+        assert_eq!(
+            versions[1].checksum_url.as_deref(),
+            Some("https://example.com/a/cpython-3.11.4.tar.gz.sha256")
+        );
+    }
+
+    #[test]
+    fn test_parse_checksum() {
+        assert_eq!(parse_checksum("abc123\n", "foo.tar.gz"), Some("abc123"));
+        let sums = "abc123  foo.tar.gz\ndef456  bar.tar.gz\n";
+        assert_eq!(parse_checksum(sums, "bar.tar.gz"), Some("def456"));
+        assert_eq!(parse_checksum(sums, "baz.tar.gz"), None);
+        let sums = "abc123  cpython-3.11.4+20230726-install_only.tar.gz\n";
+        assert_eq!(
+            parse_checksum(sums, "cpython-3.11.4+20230726-install_only.tar.gz"),
+            Some("abc123")
+        );
+    }
 }
@@ -0,0 +1,252 @@
+use std::env::join_paths;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::time::Duration;
+
+use color_eyre::eyre::{eyre, Result};
+
+use crate::cache::CacheManager;
+use crate::cmd::CmdLineRunner;
+use crate::config::{Config, Settings};
+use crate::env::{RTX_EXE, RTX_RUBY_DEFAULT_PACKAGES_FILE, RTX_RUBY_VERBOSE_INSTALL};
+use crate::file::create_dir_all;
+use crate::git::Git;
+use crate::lock_file::LockFile;
+use crate::plugins::core::help;
+use crate::plugins::{Plugin, PluginHelp, PluginName};
+use crate::toolset::{ToolVersion, ToolVersionRequest};
+use crate::ui::progress_report::ProgressReport;
+use crate::{cmd, dirs, env};
+
+#[derive(Debug)]
+pub struct RubyPlugin {
+    pub name: PluginName,
+    cache_path: PathBuf,
+    remote_version_cache: CacheManager<Vec<String>>,
+}
+
+impl RubyPlugin {
+    pub fn new(name: PluginName) -> Self {
+        let cache_path = dirs::CACHE.join(&name);
+        let fresh_duration = Some(Duration::from_secs(60 * 60 * 12)); // 12 hours
+        Self {
+            remote_version_cache: CacheManager::new(cache_path.join("remote_versions.msgpack.z"))
+                .with_fresh_duration(fresh_duration)
+                .with_fresh_file(RTX_EXE.clone()),
+            name,
+            cache_path,
+        }
+    }
+
+    fn ruby_build_path(&self) -> PathBuf {
+        self.cache_path.join("ruby-build")
+    }
+    fn ruby_build_bin(&self) -> PathBuf {
+        self.ruby_build_path().join("bin/ruby-build")
+    }
+    fn install_or_update_ruby_build(&self) -> Result<()> {
+        let _lock = self.lock_ruby_build();
+        if self.ruby_build_path().exists() {
+            self.update_ruby_build()
+        } else {
+            self.install_ruby_build()
+        }
+    }
+
+    fn lock_ruby_build(&self) -> Result<fslock::LockFile, std::io::Error> {
+        LockFile::new(&self.ruby_build_path())
+            .with_callback(|l| {
+                trace!("install_or_update_ruby_build {}", l.display());
+            })
+            .lock()
+    }
+    fn install_ruby_build(&self) -> Result<()> {
+        if self.ruby_build_path().exists() {
+            return Ok(());
+        }
+        debug!(
+            "Installing ruby-build to {}",
+            self.ruby_build_path().display()
+        );
+        create_dir_all(self.ruby_build_path().parent().unwrap())?;
+        let git = Git::new(self.ruby_build_path());
+        git.clone("https://github.com/rbenv/ruby-build.git")?;
+        Ok(())
+    }
+    fn update_ruby_build(&self) -> Result<()> {
+        // TODO: do not update if recently updated
+        debug!(
+            "Updating ruby-build in {}",
+            self.ruby_build_path().display()
+        );
+        let git = Git::new(self.ruby_build_path());
+        git.update(None)?;
+        Ok(())
+    }
+
+    fn fetch_remote_versions(&self) -> Result<Vec<String>> {
+        self.install_or_update_ruby_build()?;
+        let output = cmd!(self.ruby_build_bin(), "--definitions").read()?;
+        Ok(output
+            .lines()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect())
+    }
+
+    fn ruby_path(&self, tv: &ToolVersion) -> PathBuf {
+        tv.install_path().join("bin/ruby")
+    }
+
+    fn gem_path(&self, tv: &ToolVersion) -> PathBuf {
+        tv.install_path().join("bin/gem")
+    }
+
+    fn install_default_gems(
+        &self,
+        settings: &Settings,
+        tv: &ToolVersion,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        let body = fs::read_to_string(&*RTX_RUBY_DEFAULT_PACKAGES_FILE).unwrap_or_default();
+        for line in body.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            pr.set_message(format!("installing default gem: {}", line));
+            let gem = self.gem_path(tv);
+            let mut cmd = CmdLineRunner::new(settings, gem);
+            cmd.with_pr(pr).arg("install");
+            // lines can have extra args, e.g.: "rubocop --version 1.50.0"
+            for arg in line.split_whitespace() {
+                cmd.arg(arg);
+            }
+            let mut path = env::PATH.clone();
+            path.insert(0, tv.install_path().join("bin"));
+            cmd.env("PATH", join_paths(path)?);
+            cmd.execute()?;
+        }
+        Ok(())
+    }
+
+    fn test_ruby(&self, config: &Config, tv: &ToolVersion, pr: &ProgressReport) -> Result<()> {
+        let mut cmd = CmdLineRunner::new(&config.settings, self.ruby_path(tv));
+        cmd.with_pr(pr).arg("-v");
+        cmd.execute()
+    }
+}
+
+impl Plugin for RubyPlugin {
+    fn name(&self) -> &PluginName {
+        &self.name
+    }
+
+    fn list_remote_versions(&self, _config: &Config) -> Result<Vec<String>> {
+        self.remote_version_cache
+            .get_or_try_init(|| self.fetch_remote_versions())
+            .cloned()
+    }
+
+    fn legacy_filenames(&self, _settings: &Settings) -> Result<Vec<String>> {
+        Ok(vec![".ruby-version".into(), "Gemfile".into()])
+    }
+
+    fn parse_legacy_file(&self, path: &Path, _settings: &Settings) -> Result<String> {
+        let contents = fs::read_to_string(path)?;
+        if path.file_name().map_or(false, |f| f == "Gemfile") {
+            // most Gemfiles don't have a ruby directive, those don't set a version
+            return Ok(parse_gemfile(&contents).unwrap_or_default());
+        }
+        Ok(contents.trim().to_string())
+    }
+
+    fn external_commands(&self) -> Result<Vec<Vec<String>>> {
+        Ok(vec![vec![self.name.clone(), "rubybuild".into()]])
+    }
+
+    fn execute_external_command(&self, command: &str, args: Vec<String>) -> Result<()> {
+        match command {
+            "rubybuild" => {
+                self.install_or_update_ruby_build()?;
+                cmd::cmd(self.ruby_build_bin(), args).run()?;
+            }
+            _ => unreachable!(),
+        }
+        exit(0);
+    }
+
+    fn install_version(
+        &self,
+        config: &Config,
+        tv: &ToolVersion,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        if matches!(tv.request, ToolVersionRequest::Ref(..)) {
+            return Err(eyre!("Ref versions not supported for ruby"));
+        }
+        self.install_ruby_build()?;
+        pr.set_message("running ruby-build");
+        let mut cmd = CmdLineRunner::new(&config.settings, self.ruby_build_bin());
+        cmd.with_pr(pr).arg(tv.version.as_str());
+        if *RTX_RUBY_VERBOSE_INSTALL {
+            cmd.arg("--verbose");
+        }
+        cmd.arg(tv.install_path());
+        cmd.execute()?;
+        self.test_ruby(config, tv, pr)?;
+        self.install_default_gems(&config.settings, tv, pr)?;
+        Ok(())
+    }
+
+    fn help(&self, _config: &Config, _tv: Option<&ToolVersion>) -> Result<Option<PluginHelp>> {
+        Ok(Some(help("ruby")))
+    }
+}
+
+/// finds the version in the `ruby` directive of a Gemfile, requirements become semver ranges
+/// e.g.: `ruby "3.2.2"` -> "3.2.2", `ruby "~> 3.2.0"` -> "~3.2.0", `ruby "~> 3.2"` -> "^3.2"
+fn parse_gemfile(contents: &str) -> Option<String> {
+    let re = regex!(r#"^\s*ruby\s*\(?\s*["']([^"']+)["']"#);
+    contents.lines().find_map(|line| {
+        let v = re.captures(line)?.get(1)?.as_str().trim();
+        let v = match v.strip_prefix("~>") {
+            // the pessimistic operator allows the last segment to increase
+            Some(v) => match v.trim().split('.').count() {
+                1 | 2 => format!("^{}", v.trim()),
+                _ => format!("~{}", v.trim()),
+            },
+            None => v.split_whitespace().collect(),
+        };
+        Some(v)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_gemfile() {
+        let gemfile = indoc! {r#"
+            # frozen_string_literal: true
+
+            source "https://rubygems.org"
+            ruby '3.2.2', engine: 'ruby'
+
+            gem "rails"
+        "#};
+        assert_eq!(parse_gemfile(gemfile), Some("3.2.2".into()));
+        assert_eq!(parse_gemfile("ruby \"~> 3.1.0\"\n"), Some("~3.1.0".into()));
+        assert_eq!(parse_gemfile("ruby \"~> 3.1\"\n"), Some("^3.1".into()));
+        assert_eq!(parse_gemfile("ruby \">= 3.0\"\n"), Some(">=3.0".into()));
+        assert_eq!(parse_gemfile("gem \"rubocop\"\n"), None);
+        assert_eq!(
+            parse_gemfile("source \"https://rubygems.org\"\ngem \"rails\"\n"),
+            None
+        );
+    }
+}
@@ -8,7 +8,7 @@ use std::time::Duration;
 use color_eyre::eyre::{eyre, Result, WrapErr};
 use console::style;
 use itertools::Itertools;
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
 
 use crate::cache::CacheManager;
 use crate::cmd::cmd;
@@ -16,24 +16,29 @@ use crate::config::{Config, Settings};
 use crate::env::PREFER_STALE;
 use crate::env_diff::{EnvDiff, EnvDiffOperation};
 use crate::errors::Error::PluginNotInstalled;
-use crate::file::remove_all;
+use crate::file::{display_path, remove_all};
 use crate::git::Git;
 use crate::hash::hash_to_str;
 use crate::plugins::external_plugin_cache::ExternalPluginCache;
 use crate::plugins::rtx_plugin_toml::RtxPluginToml;
-use crate::plugins::Script::{Download, ExecEnv, Install, ParseLegacyFile};
-use crate::plugins::{Plugin, PluginName, PluginType, Script, ScriptManager};
+use crate::plugins::Script::{
+    Download, ExecEnv, HelpConfig, HelpDeps, HelpLinks, HelpOverview, Install, ParseLegacyFile,
+};
+use crate::plugins::{
+    is_prerelease, vendored_plugin_dirname, Plugin, PluginHelp, PluginName, PluginType,
+    PluginUpdate, Script, ScriptManager,
+};
 use crate::toolset::{ToolVersion, ToolVersionRequest};
 use crate::ui::progress_report::ProgressReport;
 use crate::{dirs, env, file};
 
-/// This represents a plugin installed to ~/.local/share/rtx/plugins
+/// This represents a plugin installed to ~/.local/share/rtx/plugins or vendored in a project
 #[derive(Debug)]
 pub struct ExternalPlugin {
     pub name: PluginName,
     pub plugin_path: PathBuf,
     pub repo_url: Option<String>,
-    pub toml: RtxPluginToml,
+    toml: OnceCell<RtxPluginToml>,
     cache_path: PathBuf,
     downloads_path: PathBuf,
     installs_path: PathBuf,
@@ -47,10 +52,17 @@ pub struct ExternalPlugin {
 
 impl ExternalPlugin {
     pub fn new(name: &PluginName) -> Self {
-        let plugin_path = dirs::PLUGINS.join(name);
-        let cache_path = dirs::CACHE.join(name);
-        let toml_path = plugin_path.join("rtx.plugin.toml");
-        let toml = RtxPluginToml::from_file(&toml_path).unwrap();
+        Self::from_path(name, dirs::PLUGINS.join(name))
+    }
+
+    /// a plugin in a directory outside of ~/.local/share/rtx/plugins, e.g.: one vendored in a
+    /// project with `[plugins] name = { path = "..." }`
+    pub fn from_path(name: &PluginName, plugin_path: PathBuf) -> Self {
+        let dirname = match plugin_path.starts_with(&*dirs::PLUGINS) {
+            true => name.to_string(),
+            false => vendored_plugin_dirname(name, &plugin_path),
+        };
+        let cache_path = dirs::CACHE.join(&dirname);
         let fresh_duration = if *PREFER_STALE {
             None
         } else {
@@ -59,13 +71,14 @@ impl ExternalPlugin {
         Self {
             name: name.into(),
             script_man: build_script_man(name, &plugin_path),
-            downloads_path: dirs::DOWNLOADS.join(name),
-            installs_path: dirs::INSTALLS.join(name),
+            downloads_path: dirs::DOWNLOADS.join(&dirname),
+            installs_path: dirs::INSTALLS.join(&dirname),
             cache: ExternalPluginCache::default(),
             remote_version_cache: CacheManager::new(cache_path.join("remote_versions.msgpack.z"))
                 .with_fresh_duration(fresh_duration)
                 .with_fresh_file(plugin_path.clone())
-                .with_fresh_file(plugin_path.join("bin/list-all")),
+                .with_fresh_file(plugin_path.join("bin/list-all"))
+                .with_fresh_file(plugin_path.join("rtx.plugin.toml")),
             latest_stable_cache: CacheManager::new(cache_path.join("latest_stable.msgpack.z"))
                 .with_fresh_duration(fresh_duration)
                 .with_fresh_file(plugin_path.clone())
@@ -79,18 +92,41 @@ impl ExternalPlugin {
             plugin_path,
             cache_path,
             repo_url: None,
-            toml,
+            toml: OnceCell::new(),
         }
     }
 
+    /// vendored plugins are part of the project, rtx does not clone, update or remove them
+    pub fn is_vendored(&self) -> bool {
+        !self.plugin_path.starts_with(&*dirs::PLUGINS)
+    }
+
+    /// rtx.plugin.toml is read on first use once the plugin is installed since the plugin is
+    /// often built before it is cloned
+    pub fn toml(&self) -> Result<&RtxPluginToml> {
+        static EMPTY: Lazy<RtxPluginToml> = Lazy::new(RtxPluginToml::default);
+        if !self.is_installed() {
+            return Ok(&EMPTY);
+        }
+        self.toml
+            .get_or_try_init(|| RtxPluginToml::from_file(&self.plugin_path.join("rtx.plugin.toml")))
+    }
+
+    /// the script manager with the `[scripts]` limits from rtx.plugin.toml
+    fn script_man(&self) -> Result<ScriptManager> {
+        Ok(self
+            .script_man
+            .clone()
+            .with_limits(self.toml()?.scripts.clone()))
+    }
+
     fn fetch_remote_versions(&self, settings: &Settings) -> Result<Vec<String>> {
+        if let Some(list_all) = &self.toml()?.list_all {
+            return list_all.fetch_versions(&self.plugin_path);
+        }
         let result = self
-            .script_man
-            .cmd(settings, &Script::ListAll)
-            .stdout_capture()
-            .stderr_capture()
-            .unchecked()
-            .run()
+            .script_man()?
+            .capture(settings, &Script::ListAll)
             .map_err(|err| {
                 let script = self.script_man.get_script_path(&Script::ListAll);
                 eyre!("Failed to run {}: {}", script.display(), err)
@@ -119,7 +155,7 @@ impl ExternalPlugin {
 
     fn fetch_legacy_filenames(&self, settings: &Settings) -> Result<Vec<String>> {
         let stdout =
-            self.script_man
+            self.script_man()?
                 .read(settings, &Script::ListLegacyFilenames, settings.verbose)?;
         Ok(self.parse_legacy_filenames(&stdout))
     }
@@ -128,7 +164,7 @@ impl ExternalPlugin {
     }
     fn fetch_latest_stable(&self, settings: &Settings) -> Result<Option<String>> {
         let latest_stable = self
-            .script_man
+            .script_man()?
             .read(settings, &Script::LatestStable, settings.verbose)?
             .trim()
             .to_string();
@@ -139,8 +175,8 @@ impl ExternalPlugin {
         })
     }
 
-    fn has_list_all_script(&self) -> bool {
-        self.script_man.script_exists(&Script::ListAll)
+    fn has_list_all_script(&self) -> Result<bool> {
+        Ok(self.toml()?.list_all.is_some() || self.script_man.script_exists(&Script::ListAll))
     }
     fn has_list_alias_script(&self) -> bool {
         self.script_man.script_exists(&Script::ListAliases)
@@ -153,7 +189,7 @@ impl ExternalPlugin {
     }
     fn fetch_aliases(&self, settings: &Settings) -> Result<Vec<(String, String)>> {
         let stdout = self
-            .script_man
+            .script_man()?
             .read(settings, &Script::ListAliases, settings.verbose)?;
         Ok(self.parse_aliases(&stdout))
     }
@@ -199,11 +235,14 @@ impl ExternalPlugin {
         let list_bin_paths = self.plugin_path.join("bin/list-bin-paths");
         let bin_paths = if matches!(tv.request, ToolVersionRequest::System(_)) {
             Vec::new()
+        } else if let Some(data) = &self.toml()?.list_bin_paths.data {
+            data.split_whitespace().map(|f| f.to_string()).collect()
         } else if list_bin_paths.exists() {
-            let output = self
-                .script_man_for_tv(config, tv)
-                .cmd(&config.settings, &Script::ListBinPaths)
-                .read()?;
+            let output = self.script_man_for_tv(config, tv)?.read(
+                &config.settings,
+                &Script::ListBinPaths,
+                true,
+            )?;
             output.split_whitespace().map(|f| f.to_string()).collect()
         } else {
             vec!["bin".into()]
@@ -215,8 +254,9 @@ impl ExternalPlugin {
         Ok(bin_paths)
     }
     fn fetch_exec_env(&self, config: &Config, tv: &ToolVersion) -> Result<HashMap<String, String>> {
-        let script = self.script_man_for_tv(config, tv).get_script_path(&ExecEnv);
-        let ed = EnvDiff::from_bash_script(&script, &self.script_man_for_tv(config, tv).env)?;
+        let sm = self.script_man_for_tv(config, tv)?;
+        let script = sm.get_script_path(&ExecEnv);
+        let ed = EnvDiff::from_bash_script(&script, sm.env(&config.settings))?;
         let env = ed
             .to_patches()
             .into_iter()
@@ -229,46 +269,8 @@ impl ExternalPlugin {
         Ok(env)
     }
 
-    fn script_man_for_tv(&self, config: &Config, tv: &ToolVersion) -> ScriptManager {
-        let mut sm = self.script_man.clone();
-        for (key, value) in &tv.opts {
-            let k = format!("RTX_TOOL_OPTS__{}", key.to_uppercase());
-            sm = sm.with_env(k, value.clone());
-        }
-        if let Some(project_root) = &config.project_root {
-            let project_root = project_root.to_string_lossy().to_string();
-            sm = sm.with_env("RTX_PROJECT_ROOT", project_root);
-        }
-        let install_type = match &tv.request {
-            ToolVersionRequest::Version(_, _) | ToolVersionRequest::Prefix(_, _) => "version",
-            ToolVersionRequest::Ref(_, _) => "ref",
-            ToolVersionRequest::Path(_, _) => "path",
-            ToolVersionRequest::System(_) => {
-                panic!("should not be called for system tool")
-            }
-        };
-        sm = sm
-            .with_env(
-                "RTX_INSTALL_PATH",
-                tv.install_path().to_string_lossy().to_string(),
-            )
-            .with_env(
-                "ASDF_INSTALL_PATH",
-                tv.install_path().to_string_lossy().to_string(),
-            )
-            .with_env(
-                "RTX_DOWNLOAD_PATH",
-                tv.download_path().to_string_lossy().to_string(),
-            )
-            .with_env(
-                "ASDF_DOWNLOAD_PATH",
-                tv.download_path().to_string_lossy().to_string(),
-            )
-            .with_env("RTX_INSTALL_TYPE", install_type)
-            .with_env("ASDF_INSTALL_TYPE", install_type)
-            .with_env("RTX_INSTALL_VERSION", tv.version.clone())
-            .with_env("ASDF_INSTALL_VERSION", tv.version.clone());
-        sm
+    fn script_man_for_tv(&self, config: &Config, tv: &ToolVersion) -> Result<ScriptManager> {
+        Ok(self.script_man()?.with_tv_env(config, tv))
     }
 }
 
@@ -301,9 +303,9 @@ impl Plugin for ExternalPlugin {
     fn get_type(&self) -> PluginType {
         PluginType::External
     }
-    fn list_remote_versions(&self, settings: &Settings) -> Result<Vec<String>> {
+    fn list_remote_versions(&self, config: &Config) -> Result<Vec<String>> {
         self.remote_version_cache
-            .get_or_try_init(|| self.fetch_remote_versions(settings))
+            .get_or_try_init(|| self.fetch_remote_versions(&config.settings))
             .map_err(|err| {
                 eyre!(
                     "Failed listing remote versions for plugin {}: {}",
@@ -330,7 +332,19 @@ impl Plugin for ExternalPlugin {
             .cloned()
     }
 
+    /// rtx.plugin.toml can replace the default pattern with `prerelease-regex`
+    fn is_prerelease(&self, version: &str) -> bool {
+        // an invalid rtx.plugin.toml is reported when the versions are listed
+        match self.toml().ok().and_then(|t| t.prerelease_regex.as_ref()) {
+            Some(re) => re.is_match(version),
+            None => is_prerelease(version),
+        }
+    }
+
     fn get_remote_url(&self) -> Option<String> {
+        if self.is_vendored() {
+            return None;
+        }
         let git = Git::new(self.plugin_path.to_path_buf());
         git.get_remote_url()
     }
@@ -340,6 +354,13 @@ impl Plugin for ExternalPlugin {
     }
 
     fn install(&self, config: &Config, pr: &mut ProgressReport) -> Result<()> {
+        if self.is_vendored() {
+            return Err(eyre!(
+                "plugin {} is vendored in {} which does not exist",
+                self.name,
+                display_path(&self.plugin_path)
+            ));
+        }
         let repository = self
             .repo_url
             .clone()
@@ -361,8 +382,8 @@ impl Plugin for ExternalPlugin {
         }
 
         pr.set_message("loading plugin remote versions");
-        if self.has_list_all_script() {
-            self.list_remote_versions(&config.settings)?;
+        if self.has_list_all_script()? {
+            self.list_remote_versions(config)?;
         }
         if self.has_list_alias_script() {
             pr.set_message("getting plugin aliases");
@@ -381,33 +402,65 @@ impl Plugin for ExternalPlugin {
         Ok(())
     }
 
-    fn update(&self, gitref: Option<String>) -> Result<()> {
+    fn update(
+        &self,
+        pr: &ProgressReport,
+        gitref: Option<String>,
+        dry_run: bool,
+    ) -> Result<Option<PluginUpdate>> {
         let plugin_path = self.plugin_path.to_path_buf();
+        if self.is_vendored() {
+            pr.warn(format!(
+                "Plugin: {} is vendored in {}, not updating",
+                style(&self.name).cyan().for_stderr(),
+                display_path(&plugin_path)
+            ));
+            return Ok(None);
+        }
         if plugin_path.is_symlink() {
-            warn!(
+            pr.warn(format!(
                 "Plugin: {} is a symlink, not updating",
                 style(&self.name).cyan().for_stderr()
-            );
-            return Ok(());
+            ));
+            return Ok(None);
         }
         let git = Git::new(plugin_path);
         if !git.is_repo() {
-            warn!(
+            pr.warn(format!(
                 "Plugin {} is not a git repository, not updating",
                 style(&self.name).cyan().for_stderr()
-            );
-            return Ok(());
+            ));
+            return Ok(None);
         }
-        // TODO: asdf_run_hook "pre_plugin_update"
-        let (_pre, _post) = git.update(gitref)?;
-        // TODO: asdf_run_hook "post_plugin_update"
-        Ok(())
+        let (prev_rev, post_rev) = if dry_run {
+            pr.set_message("fetching");
+            (git.current_sha()?, git.fetch(gitref)?)
+        } else {
+            pr.set_message("updating");
+            // TODO: asdf_run_hook "pre_plugin_update"
+            let revs = git.update(gitref)?;
+            // TODO: asdf_run_hook "post_plugin_update"
+            revs
+        };
+        let commits = git.log_subjects(&prev_rev, &post_rev)?;
+        Ok(Some(PluginUpdate {
+            prev_rev,
+            post_rev,
+            commits,
+        }))
     }
 
     fn uninstall(&self, pr: &ProgressReport) -> Result<()> {
         if !self.is_installed() {
             return Ok(());
         }
+        if self.is_vendored() {
+            return Err(eyre!(
+                "plugin {} is vendored in {}, remove it from [plugins] instead",
+                self.name,
+                display_path(&self.plugin_path)
+            ));
+        }
         pr.set_message("uninstalling");
 
         let rmdir = |dir: &Path| {
@@ -431,7 +484,7 @@ impl Plugin for ExternalPlugin {
     }
 
     fn get_aliases(&self, settings: &Settings) -> Result<BTreeMap<String, String>> {
-        if let Some(data) = &self.toml.list_aliases.data {
+        if let Some(data) = &self.toml()?.list_aliases.data {
             return Ok(self.parse_aliases(data).into_iter().collect());
         }
         if !self.has_list_alias_script() {
@@ -454,7 +507,7 @@ impl Plugin for ExternalPlugin {
     }
 
     fn legacy_filenames(&self, settings: &Settings) -> Result<Vec<String>> {
-        if let Some(data) = &self.toml.list_legacy_filenames.data {
+        if let Some(data) = &self.toml()?.list_legacy_filenames.data {
             return Ok(self.parse_legacy_filenames(data));
         }
         if !self.has_list_legacy_filenames_script() {
@@ -479,7 +532,9 @@ impl Plugin for ExternalPlugin {
         trace!("parsing legacy file: {}", legacy_file.to_string_lossy());
         let script = ParseLegacyFile(legacy_file.to_string_lossy().into());
         let legacy_version = match self.script_man.script_exists(&script) {
-            true => self.script_man.read(settings, &script, settings.verbose)?,
+            true => self
+                .script_man()?
+                .read(settings, &script, settings.verbose)?,
             false => fs::read_to_string(legacy_file)?,
         }
         .trim()
@@ -535,28 +590,48 @@ impl Plugin for ExternalPlugin {
         pr: &ProgressReport,
     ) -> Result<()> {
         let run_script = |script| {
-            self.script_man_for_tv(config, tv)
+            self.script_man_for_tv(config, tv)?
                 .run_by_line(&config.settings, script, pr)
         };
 
-        if self.script_man_for_tv(config, tv).script_exists(&Download) {
-            pr.set_message("downloading");
-            run_script(&Download)?;
+        let toml = self.toml()?;
+        if toml.install.is_some() {
+            toml.install_version(&self.plugin_path, tv, pr)?;
+        } else {
+            if self.script_man_for_tv(config, tv)?.script_exists(&Download) {
+                pr.set_message("downloading");
+                run_script(&Download)?;
+            }
+            pr.set_message("installing");
+            run_script(&Install)?;
         }
-        pr.set_message("installing");
-        run_script(&Install)?;
 
         Ok(())
     }
 
     fn uninstall_version(&self, config: &Config, tv: &ToolVersion) -> Result<()> {
         if self.plugin_path.join("bin/uninstall").exists() {
-            self.script_man_for_tv(config, tv)
+            self.script_man_for_tv(config, tv)?
                 .run(&config.settings, &Script::Uninstall)?;
         }
         Ok(())
     }
 
+    fn run_hook(
+        &self,
+        config: &Config,
+        tv: &ToolVersion,
+        hook: &Script,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        let sm = self.script_man_for_tv(config, tv)?;
+        if sm.script_exists(hook) {
+            pr.set_message(hook.to_string());
+            sm.run_by_line(&config.settings, hook, pr)?;
+        }
+        Ok(())
+    }
+
     fn list_bin_paths(&self, config: &Config, tv: &ToolVersion) -> Result<Vec<PathBuf>> {
         self.cache
             .list_bin_paths(config, self, tv, || self.fetch_bin_paths(config, tv))
@@ -566,6 +641,11 @@ impl Plugin for ExternalPlugin {
         if matches!(tv.request, ToolVersionRequest::System(_)) {
             return Ok(EMPTY_HASH_MAP.clone());
         }
+        let toml = self.toml()?;
+        if !toml.env.is_empty() {
+            let env = toml.exec_env(&self.plugin_path, tv)?;
+            return Ok(env.into_iter().collect());
+        }
         if !self.script_man.script_exists(&ExecEnv) || *env::__RTX_SCRIPT {
             // if the script does not exist, or we're already running from within a script,
             // the second is to prevent infinite loops
@@ -574,6 +654,30 @@ impl Plugin for ExternalPlugin {
         self.cache
             .exec_env(config, self, tv, || self.fetch_exec_env(config, tv))
     }
+
+    /// like asdf, bin/help.overview is required and the other help scripts are optional. The
+    /// scripts get ASDF_INSTALL_VERSION and the rest of the install env if a version was given
+    fn help(&self, config: &Config, tv: Option<&ToolVersion>) -> Result<Option<PluginHelp>> {
+        let sm = match tv {
+            Some(tv) if !matches!(tv.request, ToolVersionRequest::System(_)) => {
+                self.script_man_for_tv(config, tv)?
+            }
+            _ => self.script_man()?,
+        };
+        if !sm.script_exists(&HelpOverview) {
+            return Ok(None);
+        }
+        let read = |script: Script| match sm.script_exists(&script) {
+            true => sm.read(&config.settings, &script, true).map(Some),
+            false => Ok(None),
+        };
+        Ok(Some(PluginHelp {
+            overview: sm.read(&config.settings, &HelpOverview, true)?,
+            deps: read(HelpDeps)?,
+            config: read(HelpConfig)?,
+            links: read(HelpLinks)?,
+        }))
+    }
 }
 
 static EMPTY_HASH_MAP: Lazy<HashMap<String, String>> = Lazy::new(HashMap::new);
@@ -27,9 +27,10 @@ impl ExternalPluginCache {
     where
         F: FnOnce() -> Result<Vec<PathBuf>>,
     {
+        let toml = plugin.toml()?;
         let mut w = self.list_bin_paths.write().unwrap();
         let cm = w.entry(tv.request.clone()).or_insert_with(|| {
-            let list_bin_paths_filename = match &plugin.toml.list_bin_paths.cache_key {
+            let list_bin_paths_filename = match &toml.list_bin_paths.cache_key {
                 Some(key) => {
                     let key = render_cache_key(config, tv, key);
                     let filename = format!("{}.msgpack.z", key);
@@ -55,9 +56,10 @@ impl ExternalPluginCache {
     where
         F: FnOnce() -> Result<HashMap<String, String>>,
     {
+        let toml = plugin.toml()?;
         let mut w = self.exec_env.write().unwrap();
         let cm = w.entry(tv.request.clone()).or_insert_with(|| {
-            let exec_env_filename = match &plugin.toml.exec_env.cache_key {
+            let exec_env_filename = match &toml.exec_env.cache_key {
                 Some(key) => {
                     let key = render_cache_key(config, tv, key);
                     let filename = format!("{}.msgpack.z", key);
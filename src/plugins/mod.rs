@@ -9,9 +9,11 @@ pub use rtx_plugin_toml::RtxPluginToml;
 pub use script_manager::{Script, ScriptManager};
 
 use crate::config::{Config, Settings};
-use crate::toolset::ToolVersion;
+use crate::hash::hash_to_str;
+use crate::toolset::{ToolVersion, ToolVersionOptions};
 use crate::ui::progress_report::ProgressReport;
 
+pub mod backends;
 pub mod core;
 mod external_plugin;
 mod external_plugin_cache;
@@ -20,15 +22,44 @@ mod script_manager;
 
 pub type PluginName = String;
 
+/// the name of the directory used for a plugin's installs, cache and downloads
+/// e.g.: "github:BurntSushi/ripgrep" -> "github-BurntSushi-ripgrep"
+pub fn plugin_dirname(name: &str) -> String {
+    name.replace([':', '/'], "-")
+}
+
+/// vendored plugins are keyed by their path so projects vendoring different plugins with the same
+/// name do not share installs or caches
+pub fn vendored_plugin_dirname(name: &str, plugin_path: &Path) -> String {
+    format!("{}-{}", plugin_dirname(name), hash_to_str(&plugin_path))
+}
+
+/// the default for `Plugin::is_prerelease`, e.g.: "21.0.0-rc.1", "3.12.0b1" or "1.0.0-snapshot"
+pub fn is_prerelease(version: &str) -> bool {
+    regex!(
+        r"(-src|-dev|-latest|-stm|[-\\.]rc|-milestone|-alpha|-beta|[-\\.]pre|-next|(a|b|c)[0-9]+|snapshot|master)"
+    )
+    .is_match(version)
+}
+
 pub trait Plugin: Debug + Send + Sync {
     fn name(&self) -> &PluginName;
     fn get_type(&self) -> PluginType {
         PluginType::Core
     }
-    fn list_remote_versions(&self, settings: &Settings) -> Result<Vec<String>>;
+    fn list_remote_versions(&self, config: &Config) -> Result<Vec<String>>;
     fn latest_stable_version(&self, _settings: &Settings) -> Result<Option<String>> {
         Ok(None)
     }
+    /// prereleases are skipped when resolving versions unless the `prerelease` setting is on
+    fn is_prerelease(&self, version: &str) -> bool {
+        is_prerelease(version)
+    }
+    /// false if the tool options choose what to install and the version is only a label,
+    /// e.g.: crates built from `git`, in which case the remote versions are not listed
+    fn resolves_remote_versions(&self, _opts: &ToolVersionOptions) -> bool {
+        true
+    }
     fn get_remote_url(&self) -> Option<String> {
         None
     }
@@ -38,8 +69,13 @@ pub trait Plugin: Debug + Send + Sync {
     fn install(&self, _config: &Config, _pr: &mut ProgressReport) -> Result<()> {
         Ok(())
     }
-    fn update(&self, _git_ref: Option<String>) -> Result<()> {
-        Ok(())
+    fn update(
+        &self,
+        _pr: &ProgressReport,
+        _git_ref: Option<String>,
+        _dry_run: bool,
+    ) -> Result<Option<PluginUpdate>> {
+        Ok(None)
     }
     fn uninstall(&self, _pr: &ProgressReport) -> Result<()> {
         Ok(())
@@ -47,6 +83,9 @@ pub trait Plugin: Debug + Send + Sync {
     fn get_aliases(&self, _settings: &Settings) -> Result<BTreeMap<String, String>> {
         Ok(BTreeMap::new())
     }
+    fn get_alias(&self, settings: &Settings, alias: &str) -> Result<Option<String>> {
+        Ok(self.get_aliases(settings)?.get(alias).cloned())
+    }
     fn legacy_filenames(&self, _settings: &Settings) -> Result<Vec<String>> {
         Ok(vec![])
     }
@@ -65,12 +104,26 @@ pub trait Plugin: Debug + Send + Sync {
     fn uninstall_version(&self, _config: &Config, _tv: &ToolVersion) -> Result<()> {
         Ok(())
     }
+    /// runs the plugin's own script for a hook, e.g.: bin/post-install
+    fn run_hook(
+        &self,
+        _config: &Config,
+        _tv: &ToolVersion,
+        _hook: &Script,
+        _pr: &ProgressReport,
+    ) -> Result<()> {
+        Ok(())
+    }
     fn list_bin_paths(&self, _config: &Config, tv: &ToolVersion) -> Result<Vec<PathBuf>> {
         Ok(vec![tv.install_path().join("bin")])
     }
     fn exec_env(&self, _config: &Config, _tv: &ToolVersion) -> Result<HashMap<String, String>> {
         Ok(HashMap::new())
     }
+    /// documentation for `rtx plugins help`, tv is set if a version was given
+    fn help(&self, _config: &Config, _tv: Option<&ToolVersion>) -> Result<Option<PluginHelp>> {
+        Ok(None)
+    }
 }
 
 pub enum PluginType {
@@ -79,6 +132,44 @@ pub enum PluginType {
     External,
 }
 
+/// the commits a plugin was (or with --dry-run would be) updated between
+#[derive(Debug, Clone)]
+pub struct PluginUpdate {
+    pub prev_rev: String,
+    pub post_rev: String,
+    /// subjects of the commits after prev_rev up to post_rev, newest first
+    pub commits: Vec<String>,
+}
+
+impl PluginUpdate {
+    pub fn is_up_to_date(&self) -> bool {
+        self.prev_rev == self.post_rev
+    }
+
+    /// e.g.: "a94de87..6cf70c1"
+    pub fn short_revs(&self) -> String {
+        format!(
+            "{}..{}",
+            short_sha(&self.prev_rev),
+            short_sha(&self.post_rev)
+        )
+    }
+}
+
+/// the sections of `rtx plugins help`, external plugins provide these with the asdf help scripts,
+/// e.g.: bin/help.overview
+#[derive(Debug, Default, Clone)]
+pub struct PluginHelp {
+    pub overview: String,
+    pub deps: Option<String>,
+    pub config: Option<String>,
+    pub links: Option<String>,
+}
+
+pub fn short_sha(sha: &str) -> &str {
+    &sha[..sha.len().min(7)]
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_str_eq;
@@ -92,24 +183,52 @@ mod tests {
     #[test]
     fn test_exact_match() {
         assert_cli!("plugin", "add", "tiny");
+        let config = Config::load().unwrap();
         let settings = Settings::default();
         let plugin = ExternalPlugin::new(&PluginName::from("tiny"));
         let tool = Tool::new(plugin.name.clone(), Box::new(plugin));
         let version = tool
-            .latest_version(&settings, Some("1.0.0".into()))
+            .latest_version(&config, &settings, Some("1.0.0".into()))
             .unwrap()
             .unwrap();
         assert_str_eq!(version, "1.0.0");
-        let version = tool.latest_version(&settings, None).unwrap().unwrap();
+        let version = tool
+            .latest_version(&config, &settings, None)
+            .unwrap()
+            .unwrap();
         assert_str_eq!(version, "3.1.0");
     }
 
     #[test]
     fn test_latest_stable() {
+        let config = Config::load().unwrap();
         let settings = Settings::default();
         let plugin = ExternalPlugin::new(&PluginName::from("dummy"));
         let tool = Tool::new(plugin.name.clone(), Box::new(plugin));
-        let version = tool.latest_version(&settings, None).unwrap().unwrap();
+        let version = tool
+            .latest_version(&config, &settings, None)
+            .unwrap()
+            .unwrap();
         assert_str_eq!(version, "2.0.0");
     }
+
+    #[test]
+    fn test_is_prerelease() {
+        assert!(is_prerelease("21.0.0-rc.1"));
+        assert!(is_prerelease("3.12.0b1"));
+        assert!(is_prerelease("2.0.0-beta"));
+        assert!(!is_prerelease("20.5.1"));
+        assert!(!is_prerelease("temurin-17.0.9"));
+    }
+
+    #[test]
+    fn test_plugin_update_short_revs() {
+        let update = PluginUpdate {
+            prev_rev: "a94de87b1f0e3c4e1b9ef4c4f4f6c2c9d7a1b2c3".into(),
+            post_rev: "6cf70c1e8d7f0a1b2c3d4e5f60718293a4b5c6d7".into(),
+            commits: vec!["Fix list-all".into()],
+        };
+        assert!(!update.is_up_to_date());
+        assert_str_eq!(update.short_revs(), "a94de87..6cf70c1");
+    }
 }
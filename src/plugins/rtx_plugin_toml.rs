@@ -1,11 +1,21 @@
+use std::collections::BTreeMap;
 use std::fs;
+use std::os::unix::fs::PermissionsExt;
 use std::path::Path;
+use std::time::Duration;
 
 use color_eyre::eyre::eyre;
 use color_eyre::{Result, Section};
+use itertools::Itertools;
+use regex::Regex;
+use tera::Context;
 use toml_edit::{Document, Item, Value};
+use versions::Versioning;
 
-use crate::parse_error;
+use crate::tera::{get_tera, BASE_CONTEXT};
+use crate::toolset::ToolVersion;
+use crate::ui::progress_report::ProgressReport;
+use crate::{cmd, file, http, parse_error};
 
 #[derive(Debug, Default, Clone)]
 pub struct RtxPluginTomlScriptConfig {
@@ -13,12 +23,51 @@ pub struct RtxPluginTomlScriptConfig {
     pub data: Option<String>,
 }
 
+/// overrides the plugin_script_timeout and plugin_script_retries settings
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RtxPluginTomlScriptLimits {
+    pub timeout: Option<Duration>,
+    pub retries: Option<usize>,
+}
+
+/// `[scripts]` sets limits for all of the plugin's scripts, `[scripts.<script>]` for one of them
+#[derive(Debug, Default, Clone)]
+pub struct RtxPluginTomlScripts {
+    pub default: RtxPluginTomlScriptLimits,
+    pub scripts: BTreeMap<String, RtxPluginTomlScriptLimits>,
+}
+
+/// replaces bin/list-all, versions come from the tags of a git repo or from a json document
+#[derive(Debug, Default, Clone)]
+pub struct RtxPluginTomlListAll {
+    pub git: Option<String>,
+    pub url: Option<String>,
+    pub selector: Option<String>,
+    pub version_regex: Option<String>,
+}
+
+/// replaces bin/download and bin/install, downloads an archive or executable from a url template
+#[derive(Debug, Default, Clone)]
+pub struct RtxPluginTomlInstall {
+    pub url: String,
+    pub strip_components: usize,
+    pub bin: Option<String>,
+    pub os: BTreeMap<String, String>,
+    pub arch: BTreeMap<String, String>,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct RtxPluginToml {
     pub exec_env: RtxPluginTomlScriptConfig,
     pub list_aliases: RtxPluginTomlScriptConfig,
     pub list_bin_paths: RtxPluginTomlScriptConfig,
     pub list_legacy_filenames: RtxPluginTomlScriptConfig,
+    pub list_all: Option<RtxPluginTomlListAll>,
+    pub install: Option<RtxPluginTomlInstall>,
+    pub env: BTreeMap<String, String>,
+    pub scripts: RtxPluginTomlScripts,
+    /// replaces the default pattern of the versions that are prereleases
+    pub prerelease_regex: Option<Regex>,
 }
 
 impl RtxPluginToml {
@@ -49,6 +98,16 @@ impl RtxPluginToml {
                 "list-legacy-filenames" => {
                     self.list_legacy_filenames = self.parse_script_config(k, v)?
                 }
+                "list-all" => self.list_all = Some(self.parse_list_all(k, v)?),
+                "install" => self.install = Some(self.parse_install(k, v)?),
+                "env" => self.env = self.parse_string_map(k, v)?,
+                "scripts" => self.scripts = self.parse_scripts(k, v)?,
+                "prerelease-regex" => match v.as_value() {
+                    Some(v) => {
+                        self.prerelease_regex = Some(Regex::new(&self.parse_string(k, v)?)?);
+                    }
+                    _ => parse_error!(k, v, "string")?,
+                },
                 // this is an old key used in rtx-python
                 // this file is invalid, so just stop parsing entirely if we see it
                 "legacy-filenames" => return Ok(()),
@@ -79,6 +138,135 @@ impl RtxPluginToml {
         }
     }
 
+    fn parse_list_all(&mut self, key: &str, v: &Item) -> Result<RtxPluginTomlListAll> {
+        match v.as_table_like() {
+            Some(table) => {
+                let mut config = RtxPluginTomlListAll::default();
+                for (k, v) in table.iter() {
+                    let key = format!("{}.{}", key, k);
+                    let v = match v.as_value() {
+                        Some(v) => v,
+                        None => return parse_error!(key, v, "string"),
+                    };
+                    match k {
+                        "git" => config.git = Some(self.parse_string(&key, v)?),
+                        "url" => config.url = Some(self.parse_string(&key, v)?),
+                        "selector" => config.selector = Some(self.parse_string(&key, v)?),
+                        "version-regex" => {
+                            let re = self.parse_string(&key, v)?;
+                            Regex::new(&re)?;
+                            config.version_regex = Some(re);
+                        }
+                        _ => parse_error!(key, v, "one of: git, url, selector, version-regex")?,
+                    }
+                }
+                if config.git.is_none() == config.url.is_none() {
+                    return Err(eyre!("{key} must have one of: git, url"));
+                }
+                Ok(config)
+            }
+            _ => parse_error!(key, v, "table")?,
+        }
+    }
+
+    fn parse_install(&mut self, key: &str, v: &Item) -> Result<RtxPluginTomlInstall> {
+        match v.as_table_like() {
+            Some(table) => {
+                let mut url = None;
+                let mut config = RtxPluginTomlInstall::default();
+                for (k, v) in table.iter() {
+                    let key = format!("{}.{}", key, k);
+                    match k {
+                        "url" => match v.as_value() {
+                            Some(v) => url = Some(self.parse_string(&key, v)?),
+                            _ => parse_error!(key, v, "string")?,
+                        },
+                        "bin" => match v.as_value() {
+                            Some(v) => config.bin = Some(self.parse_string(&key, v)?),
+                            _ => parse_error!(key, v, "string")?,
+                        },
+                        "strip-components" => match v.as_integer() {
+                            Some(n) if n >= 0 => config.strip_components = n as usize,
+                            _ => parse_error!(key, v, "positive integer")?,
+                        },
+                        "os" => config.os = self.parse_string_map(&key, v)?,
+                        "arch" => config.arch = self.parse_string_map(&key, v)?,
+                        _ => parse_error!(key, v, "one of: url, bin, strip-components, os, arch")?,
+                    }
+                }
+                config.url = url.ok_or_else(|| eyre!("{key}.url is required"))?;
+                Ok(config)
+            }
+            _ => parse_error!(key, v, "table")?,
+        }
+    }
+
+    fn parse_scripts(&mut self, key: &str, v: &Item) -> Result<RtxPluginTomlScripts> {
+        match v.as_table_like() {
+            Some(table) => {
+                let mut config = RtxPluginTomlScripts::default();
+                for (k, v) in table.iter() {
+                    let key = format!("{}.{}", key, k);
+                    match k {
+                        "timeout" | "retries" => {
+                            self.parse_script_limit(&mut config.default, &key, k, v)?
+                        }
+                        _ => match v.as_table_like() {
+                            Some(script_table) => {
+                                let limits = config.scripts.entry(k.to_string()).or_default();
+                                for (limit, v) in script_table.iter() {
+                                    let key = format!("{}.{}", key, limit);
+                                    self.parse_script_limit(limits, &key, limit, v)?;
+                                }
+                            }
+                            _ => parse_error!(key, v, "one of: timeout, retries or a table")?,
+                        },
+                    }
+                }
+                Ok(config)
+            }
+            _ => parse_error!(key, v, "table")?,
+        }
+    }
+
+    fn parse_script_limit(
+        &mut self,
+        limits: &mut RtxPluginTomlScriptLimits,
+        key: &str,
+        limit: &str,
+        v: &Item,
+    ) -> Result<()> {
+        match limit {
+            "timeout" => match v.as_str().map(humantime::parse_duration) {
+                Some(Ok(timeout)) => limits.timeout = Some(timeout),
+                _ => parse_error!(key, v, "duration")?,
+            },
+            "retries" => match v.as_integer() {
+                Some(n) if n >= 0 => limits.retries = Some(n as usize),
+                _ => parse_error!(key, v, "positive integer")?,
+            },
+            _ => parse_error!(key, v, "one of: timeout, retries")?,
+        }
+        Ok(())
+    }
+
+    fn parse_string_map(&mut self, key: &str, v: &Item) -> Result<BTreeMap<String, String>> {
+        match v.as_table_like() {
+            Some(table) => {
+                let mut map = BTreeMap::new();
+                for (k, v) in table.iter() {
+                    let key = format!("{}.{}", key, k);
+                    match v.as_value() {
+                        Some(v) => map.insert(k.to_string(), self.parse_string(&key, v)?),
+                        _ => parse_error!(key, v, "string")?,
+                    };
+                }
+                Ok(map)
+            }
+            _ => parse_error!(key, v, "table")?,
+        }
+    }
+
     fn parse_string_array(&mut self, k: &str, v: &Item) -> Result<Vec<String>> {
         match v.as_array() {
             Some(arr) => {
@@ -100,12 +288,199 @@ impl RtxPluginToml {
     }
 }
 
+impl RtxPluginTomlScripts {
+    pub fn timeout(&self, script: &str) -> Option<Duration> {
+        self.scripts
+            .get(script)
+            .and_then(|l| l.timeout)
+            .or(self.default.timeout)
+    }
+
+    pub fn retries(&self, script: &str) -> Option<usize> {
+        self.scripts
+            .get(script)
+            .and_then(|l| l.retries)
+            .or(self.default.retries)
+    }
+}
+
+impl RtxPluginToml {
+    /// renders a template with `version`, `install_path`, `os` and `arch`
+    /// os and arch are mapped through the `install.os` and `install.arch` tables if set
+    pub fn render(&self, plugin_path: &Path, tv: &ToolVersion, tmpl: &str) -> Result<String> {
+        let (os, arch) = (std::env::consts::OS, std::env::consts::ARCH);
+        let install = self.install.clone().unwrap_or_default();
+        let mut ctx = BASE_CONTEXT.clone();
+        ctx.insert("version", &tv.version);
+        ctx.insert("install_path", &tv.install_path());
+        ctx.insert("os", install.os.get(os).map_or(os, |s| s.as_str()));
+        ctx.insert("arch", install.arch.get(arch).map_or(arch, |s| s.as_str()));
+        render_str(plugin_path, tmpl, &ctx)
+    }
+
+    pub fn exec_env(
+        &self,
+        plugin_path: &Path,
+        tv: &ToolVersion,
+    ) -> Result<BTreeMap<String, String>> {
+        self.env
+            .iter()
+            .map(|(k, v)| Ok((k.clone(), self.render(plugin_path, tv, v)?)))
+            .collect()
+    }
+
+    pub fn install_version(
+        &self,
+        plugin_path: &Path,
+        tv: &ToolVersion,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        let install = self
+            .install
+            .as_ref()
+            .ok_or_else(|| eyre!("no [install] section in rtx.plugin.toml"))?;
+        let url = self.render(plugin_path, tv, &install.url)?;
+        let filename = url.rsplit('/').next().unwrap_or_default();
+        let is_tarball = filename.ends_with(".tar.gz") || filename.ends_with(".tgz");
+        let is_archive =
+            regex!(r"\.(zip|tar|tar\.xz|tar\.bz2|txz|gz|xz|bz2|7z)$").is_match(filename);
+        if is_archive && !is_tarball {
+            return Err(eyre!(
+                "unsupported archive format: {filename}, only .tar.gz archives can be extracted"
+            ));
+        }
+        let download_path = tv.download_path().join(filename);
+
+        pr.set_message(format!("downloading {url}"));
+        http::Client::new()?.download_file(&url, &download_path)?;
+
+        pr.set_message(format!("installing {filename}"));
+        file::remove_all(tv.install_path())?;
+        if is_tarball {
+            let extract_path = tv.download_path().join("extract");
+            file::remove_all(&extract_path)?;
+            file::untar(&download_path, &extract_path)?;
+            let mut top = extract_path;
+            for _ in 0..install.strip_components {
+                top = match file::dir_subdirs(&top)?.as_slice() {
+                    [dir] => top.join(dir),
+                    _ => {
+                        return Err(eyre!(
+                            "{filename} does not have a single top-level directory"
+                        ))
+                    }
+                };
+            }
+            fs::rename(top, tv.install_path())?;
+        } else {
+            // a bare executable
+            let bin = install.bin.as_deref().unwrap_or(filename);
+            let bin_path = tv.install_path().join("bin").join(bin);
+            file::create_dir_all(bin_path.parent().unwrap())?;
+            fs::copy(&download_path, &bin_path)?;
+            fs::set_permissions(&bin_path, fs::Permissions::from_mode(0o755))?;
+        }
+        Ok(())
+    }
+}
+
+impl RtxPluginTomlListAll {
+    pub fn fetch_versions(&self, plugin_path: &Path) -> Result<Vec<String>> {
+        let ctx = BASE_CONTEXT.clone();
+        let versions = match (&self.git, &self.url) {
+            (Some(git), _) => {
+                let git = render_str(plugin_path, git, &ctx)?;
+                let output = cmd!("git", "ls-remote", "--tags", "--refs", git).read()?;
+                parse_git_tags(&output)
+            }
+            (None, Some(url)) => {
+                let url = render_str(plugin_path, url, &ctx)?;
+                let body = http::Client::new()?.get_text(url)?;
+                let json: serde_json::Value = serde_json::from_str(&body)?;
+                select_json(&json, self.selector.as_deref().unwrap_or("[*]"))
+            }
+            (None, None) => vec![],
+        };
+        let re = match &self.version_regex {
+            Some(re) => Regex::new(re)?,
+            None => Regex::new(r"^v?([0-9].*)$")?,
+        };
+        Ok(versions
+            .iter()
+            .filter_map(|v| {
+                let caps = re.captures(v)?;
+                caps.get(1).or(caps.get(0)).map(|m| m.as_str().to_string())
+            })
+            .unique()
+            .sorted_by_cached_key(|v| Versioning::new(v))
+            .collect())
+    }
+}
+
+fn render_str(plugin_path: &Path, tmpl: &str, ctx: &Context) -> Result<String> {
+    get_tera(plugin_path)
+        .render_str(tmpl, ctx)
+        .map_err(|err| eyre!("failed to parse template: {} {}", tmpl, err))
+}
+
+/// e.g.: "abc123\trefs/tags/v1.0.0" -> "v1.0.0"
+fn parse_git_tags(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| line.split_once("refs/tags/"))
+        .map(|(_, tag)| tag.trim().to_string())
+        .collect()
+}
+
+/// a small subset of JSONPath, e.g.: "releases[*].tag_name" or "*.version"
+/// `*` selects every element of an array or every value of an object
+fn select_json(json: &serde_json::Value, selector: &str) -> Vec<String> {
+    let all = |v: &serde_json::Value| -> Vec<serde_json::Value> {
+        match v {
+            serde_json::Value::Array(a) => a.clone(),
+            serde_json::Value::Object(o) => o.values().cloned().collect(),
+            _ => vec![],
+        }
+    };
+    let mut values = vec![json.clone()];
+    for token in selector.split('.').filter(|t| !t.is_empty()) {
+        let (key, index) = match token.split_once('[') {
+            Some((key, index)) => (key, Some(index.trim_end_matches(']'))),
+            None => (token, None),
+        };
+        values = match key {
+            "" => values,
+            "*" => values.iter().flat_map(all).collect(),
+            key => values.iter().filter_map(|v| v.get(key).cloned()).collect(),
+        };
+        values = match index {
+            None => values,
+            Some("*") => values.iter().flat_map(all).collect(),
+            Some(i) => match i.parse::<usize>() {
+                Ok(i) => values.iter().filter_map(|v| v.get(i).cloned()).collect(),
+                Err(_) => vec![],
+            },
+        };
+    }
+    values
+        .into_iter()
+        .filter_map(|v| match v {
+            serde_json::Value::String(s) => Some(s),
+            serde_json::Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use indoc::formatdoc;
     use insta::assert_debug_snapshot;
 
     use crate::dirs;
+    use crate::plugins::ExternalPlugin;
+    use crate::tool::Tool;
+    use crate::toolset::ToolVersionRequest;
 
     use super::*;
 
@@ -142,6 +517,123 @@ mod tests {
         "###);
     }
 
+    #[test]
+    fn test_declarative() {
+        let cf = parse(&formatdoc! {r#"
+        [list-all]
+        git = "https://github.com/BurntSushi/ripgrep"
+        version-regex = '^([0-9.]+)$'
+        [install]
+        url = "https://github.com/BurntSushi/ripgrep/releases/download/{{{{version}}}}/ripgrep-{{{{version}}}}-{{{{arch}}}}-{{{{os}}}}.tar.gz"
+        strip-components = 1
+        os = {{ linux = "unknown-linux-musl", macos = "apple-darwin" }}
+        [list-bin-paths]
+        data = "."
+        [env]
+        RIPGREP_CONFIG_PATH = "{{{{install_path}}}}/ripgreprc"
+        "#});
+
+        assert_debug_snapshot!(cf.list_all, @r###"
+        Some(
+            RtxPluginTomlListAll {
+                git: Some(
+                    "https://github.com/BurntSushi/ripgrep",
+                ),
+                url: None,
+                selector: None,
+                version_regex: Some(
+                    "^([0-9.]+)$",
+                ),
+            },
+        )
+        "###);
+        let install = cf.install.unwrap();
+        assert_eq!(install.strip_components, 1);
+        assert_eq!(install.os["linux"], "unknown-linux-musl");
+        assert_eq!(cf.list_bin_paths.data.unwrap(), ".");
+        assert_eq!(cf.env["RIPGREP_CONFIG_PATH"], "{{install_path}}/ripgreprc");
+    }
+
+    #[test]
+    fn test_declarative_errors() {
+        let mut cf = RtxPluginToml::init();
+        assert!(cf.parse("[list-all]\nselector = \"[*]\"").is_err());
+        assert!(cf.parse("[install]\nstrip-components = 1").is_err());
+    }
+
+    #[test]
+    fn test_install_unsupported_archive() {
+        let cf = parse("[install]\nurl = \"file:///tmp/tool-{{version}}.zip\"");
+        let tool = Tool::new("tiny".into(), Box::new(ExternalPlugin::new(&"tiny".into())));
+        let request = ToolVersionRequest::Version("tiny".into(), "1.0.0".into());
+        let tv = ToolVersion::new(&tool, request, Default::default(), "1.0.0".into());
+        let pr = ProgressReport::new(true);
+        let err = cf.install_version(&tool.plugin_path, &tv, &pr).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unsupported archive format: tool-1.0.0.zip, only .tar.gz archives can be extracted"
+        );
+    }
+
+    #[test]
+    fn test_scripts() {
+        let cf = parse(&formatdoc! {r#"
+        [scripts]
+        timeout = "1m"
+        retries = 2
+        [scripts.install]
+        timeout = "1h"
+        "#});
+
+        assert_eq!(
+            cf.scripts.timeout("list-all"),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            cf.scripts.timeout("install"),
+            Some(Duration::from_secs(3600))
+        );
+        assert_eq!(cf.scripts.retries("install"), Some(2));
+        let mut cf = RtxPluginToml::init();
+        assert!(cf.parse("[scripts]\ntimeout = 30").is_err());
+        assert!(cf.parse("[scripts.install]\nretry = 1").is_err());
+    }
+
+    #[test]
+    fn test_prerelease_regex() {
+        let cf = parse(r#"prerelease-regex = '-(rc|beta)\.[0-9]+$'"#);
+        let re = cf.prerelease_regex.unwrap();
+        assert!(re.is_match("2.0.0-rc.1"));
+        assert!(!re.is_match("2.0.0-alpha"));
+        let mut cf = RtxPluginToml::init();
+        assert!(cf.parse("prerelease-regex = '(rc'").is_err());
+        assert!(cf.parse("prerelease-regex = true").is_err());
+    }
+
+    #[test]
+    fn test_parse_git_tags() {
+        let output = "abc123\trefs/tags/v1.0.0\ndef456\trefs/tags/1.1.0\n";
+        assert_eq!(parse_git_tags(output), vec!["v1.0.0", "1.1.0"]);
+    }
+
+    #[test]
+    fn test_select_json() {
+        let json = serde_json::json!({
+            "releases": [{"tag_name": "v1.0.0"}, {"tag_name": "v1.1.0"}],
+            "versions": {"2.0.0": {"version": "2.0.0"}},
+        });
+        assert_eq!(
+            select_json(&json, "releases[*].tag_name"),
+            vec!["v1.0.0", "v1.1.0"]
+        );
+        assert_eq!(select_json(&json, "releases[1].tag_name"), vec!["v1.1.0"]);
+        assert_eq!(select_json(&json, "versions.*.version"), vec!["2.0.0"]);
+        assert_eq!(
+            select_json(&serde_json::json!(["1.0", "2.0"]), "[*]"),
+            vec!["1.0", "2.0"]
+        );
+    }
+
     fn parse(s: &str) -> RtxPluginToml {
         let mut cf = RtxPluginToml::init();
         cf.parse(s).unwrap();
@@ -1,37 +1,45 @@
 use crate::fake_asdf::get_path_with_fake_asdf;
 use std::collections::HashMap;
-use std::ffi::OsString;
+use std::ffi::{OsStr, OsString};
 use std::fmt;
 use std::fmt::{Display, Formatter};
+use std::os::unix::process::CommandExt;
 use std::path::PathBuf;
 use std::process::Output;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use color_eyre::eyre::{Context, Result};
 use duct::Expression;
 use indexmap::indexmap;
 use once_cell::sync::Lazy;
 
-use crate::cmd::{cmd, CmdLineRunner};
-use crate::config::Settings;
+use crate::cmd::{cmd, CmdLineRunner, ProcessGroup};
+use crate::config::{Config, Settings};
 use crate::errors::Error;
-use crate::errors::Error::ScriptFailed;
+use crate::errors::Error::{ScriptFailed, ScriptTimeout};
 use crate::file::{basename, display_path};
+use crate::plugins::rtx_plugin_toml::RtxPluginTomlScripts;
+use crate::toolset::{ToolVersion, ToolVersionRequest};
 use crate::ui::progress_report::ProgressReport;
-use crate::{dirs, env};
+use crate::{dirs, env, file};
 
 #[derive(Debug, Clone)]
 pub struct ScriptManager {
     pub plugin_path: PathBuf,
     pub plugin_name: String,
+    /// the variables rtx sets for scripts, these are added to PRISTINE_ENV when scripts are run
     pub env: HashMap<OsString, OsString>,
+    pub limits: RtxPluginTomlScripts,
 }
 
 #[derive(Debug, Clone)]
 pub enum Script {
-    // PreInstall,
-    // PostInstall,
-    // PreUninstall,
-    // PostUninstall,
+    // Hooks
+    PreInstall,
+    PostInstall,
+    PreUninstall,
+    PostUninstall,
 
     // Plugin
     LatestStable,
@@ -40,6 +48,12 @@ pub enum Script {
     ListLegacyFilenames,
     ParseLegacyFile(String),
 
+    // Help
+    HelpOverview,
+    HelpDeps,
+    HelpConfig,
+    HelpLinks,
+
     // RuntimeVersion
     Download,
     ExecEnv,
@@ -48,9 +62,29 @@ pub enum Script {
     Uninstall,
 }
 
+impl Script {
+    /// hooks and uninstall change more than the download and install dirs, so they are not retried
+    fn is_retryable(&self) -> bool {
+        !matches!(
+            self,
+            Script::PreInstall
+                | Script::PostInstall
+                | Script::PreUninstall
+                | Script::PostUninstall
+                | Script::Uninstall
+        )
+    }
+}
+
 impl Display for Script {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
+            // Hooks
+            Script::PreInstall => write!(f, "pre-install"),
+            Script::PostInstall => write!(f, "post-install"),
+            Script::PreUninstall => write!(f, "pre-uninstall"),
+            Script::PostUninstall => write!(f, "post-uninstall"),
+
             // Plugin
             Script::LatestStable => write!(f, "latest-stable"),
             Script::ListAll => write!(f, "list-all"),
@@ -58,6 +92,12 @@ impl Display for Script {
             Script::ListAliases => write!(f, "list-aliases"),
             Script::ParseLegacyFile(_) => write!(f, "parse-legacy-file"),
 
+            // Help
+            Script::HelpOverview => write!(f, "help.overview"),
+            Script::HelpDeps => write!(f, "help.deps"),
+            Script::HelpConfig => write!(f, "help.config"),
+            Script::HelpLinks => write!(f, "help.links"),
+
             // RuntimeVersion
             Script::Install => write!(f, "install"),
             Script::Uninstall => write!(f, "uninstall"),
@@ -69,24 +109,18 @@ impl Display for Script {
 }
 
 static INITIAL_ENV: Lazy<HashMap<OsString, OsString>> = Lazy::new(|| {
-    let mut env: HashMap<OsString, OsString> = env::PRISTINE_ENV
-        .iter()
-        .map(|(k, v)| (k.into(), v.into()))
-        .collect();
-    env.extend(
-        (indexmap! {
-            "__RTX_SCRIPT" => "1".to_string(),
-            "ASDF_CONCURRENCY" => num_cpus::get().to_string(),
-            "PATH" => get_path_with_fake_asdf(),
-            "RTX_CACHE_DIR" => env::RTX_CACHE_DIR.to_string_lossy().to_string(),
-            "RTX_CONCURRENCY" => num_cpus::get().to_string(),
-            "RTX_DATA_DIR" => dirs::ROOT.to_string_lossy().to_string(),
-            "RTX_EXE" => env::RTX_EXE.to_string_lossy().to_string(),
-        })
-        .into_iter()
-        .map(|(k, v)| (k.into(), v.into())),
-    );
-    env
+    (indexmap! {
+        "__RTX_SCRIPT" => "1".to_string(),
+        "ASDF_CONCURRENCY" => num_cpus::get().to_string(),
+        "PATH" => get_path_with_fake_asdf(),
+        "RTX_CACHE_DIR" => env::RTX_CACHE_DIR.to_string_lossy().to_string(),
+        "RTX_CONCURRENCY" => num_cpus::get().to_string(),
+        "RTX_DATA_DIR" => dirs::ROOT.to_string_lossy().to_string(),
+        "RTX_EXE" => env::RTX_EXE.to_string_lossy().to_string(),
+    })
+    .into_iter()
+    .map(|(k, v)| (k.into(), v.into()))
+    .collect()
 });
 
 impl ScriptManager {
@@ -94,10 +128,16 @@ impl ScriptManager {
         Self {
             plugin_name: basename(&plugin_path).expect("invalid plugin path"),
             env: INITIAL_ENV.clone(),
+            limits: RtxPluginTomlScripts::default(),
             plugin_path,
         }
     }
 
+    pub fn with_limits(mut self, limits: RtxPluginTomlScripts) -> Self {
+        self.limits = limits;
+        self
+    }
+
     pub fn with_env<K, V>(mut self, k: K, v: V) -> Self
     where
         K: Into<OsString>,
@@ -107,6 +147,49 @@ impl ScriptManager {
         self
     }
 
+    /// the install env of a version, e.g.: ASDF_INSTALL_VERSION and RTX_TOOL_OPTS__*
+    pub fn with_tv_env(mut self, config: &Config, tv: &ToolVersion) -> Self {
+        for (key, value) in &tv.opts {
+            let k = format!("RTX_TOOL_OPTS__{}", key.to_uppercase());
+            self = self.with_env(k, value.clone());
+        }
+        if let Some(project_root) = &config.project_root {
+            let project_root = project_root.to_string_lossy().to_string();
+            self = self.with_env("RTX_PROJECT_ROOT", project_root);
+        }
+        let install_type = match &tv.request {
+            ToolVersionRequest::Version(_, _)
+            | ToolVersionRequest::Prefix(_, _)
+            | ToolVersionRequest::Range(_, _)
+            | ToolVersionRequest::Sub { .. } => "version",
+            ToolVersionRequest::Ref(_, _) => "ref",
+            ToolVersionRequest::Path(_, _) => "path",
+            ToolVersionRequest::System(_) => {
+                panic!("should not be called for system tool")
+            }
+        };
+        self.with_env(
+            "RTX_INSTALL_PATH",
+            tv.install_path().to_string_lossy().to_string(),
+        )
+        .with_env(
+            "ASDF_INSTALL_PATH",
+            tv.install_path().to_string_lossy().to_string(),
+        )
+        .with_env(
+            "RTX_DOWNLOAD_PATH",
+            tv.download_path().to_string_lossy().to_string(),
+        )
+        .with_env(
+            "ASDF_DOWNLOAD_PATH",
+            tv.download_path().to_string_lossy().to_string(),
+        )
+        .with_env("RTX_INSTALL_TYPE", install_type)
+        .with_env("ASDF_INSTALL_TYPE", install_type)
+        .with_env("RTX_INSTALL_VERSION", tv.version.clone())
+        .with_env("ASDF_INSTALL_VERSION", tv.version.clone())
+    }
+
     pub fn get_script_path(&self, script: &Script) -> PathBuf {
         self.plugin_path.join("bin").join(script.to_string())
     }
@@ -115,6 +198,33 @@ impl ScriptManager {
         self.get_script_path(script).is_file()
     }
 
+    /// the user's environment filtered by plugin_script_env_allowlist and
+    /// plugin_script_env_denylist with the variables rtx sets, which are always passed
+    pub fn env(&self, settings: &Settings) -> HashMap<OsString, OsString> {
+        let mut env: HashMap<OsString, OsString> = env::PRISTINE_ENV
+            .iter()
+            .filter(|(k, _)| is_env_allowed(settings, k))
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect();
+        env.extend(self.env.clone());
+        env
+    }
+
+    /// `[scripts]` in rtx.plugin.toml takes precedence over plugin_script_timeout, 0 disables it
+    fn timeout(&self, settings: &Settings, script: &Script) -> Option<Duration> {
+        let timeout = self
+            .limits
+            .timeout(&script.to_string())
+            .unwrap_or(settings.plugin_script_timeout);
+        (!timeout.is_zero()).then_some(timeout)
+    }
+
+    fn retries(&self, settings: &Settings, script: &Script) -> usize {
+        self.limits
+            .retries(&script.to_string())
+            .unwrap_or(settings.plugin_script_retries)
+    }
+
     pub fn cmd(&self, settings: &Settings, script: &Script) -> Expression {
         let args = match script {
             Script::ParseLegacyFile(filename) => vec![filename.clone()],
@@ -124,7 +234,7 @@ impl ScriptManager {
         // if !script_path.exists() {
         //     return Err(PluginNotInstalled(self.plugin_name.clone()).into());
         // }
-        let mut cmd = cmd(script_path, args).full_env(&self.env);
+        let mut cmd = cmd(script_path, args).full_env(self.env(settings));
         if !settings.raw {
             // ignore stdin, otherwise a prompt may show up where the user won't see it
             cmd = cmd.stdin_null();
@@ -134,7 +244,7 @@ impl ScriptManager {
 
     pub fn run(&self, settings: &Settings, script: &Script) -> Result<()> {
         let cmd = self.cmd(settings, script);
-        let Output { status, .. } = cmd.unchecked().run()?;
+        let Output { status, .. } = self.output(settings, script, cmd)?;
 
         match status.success() {
             true => Ok(()),
@@ -149,8 +259,20 @@ impl ScriptManager {
         if !verbose && !settings.raw {
             cmd = cmd.stderr_null();
         }
-        cmd.read()
-            .with_context(|| ScriptFailed(display_path(&self.get_script_path(script)), None))
+        let output = self.output(settings, script, cmd.stdout_capture())?;
+        if !output.status.success() {
+            let path = display_path(&self.get_script_path(script));
+            return Err(ScriptFailed(path, Some(output.status)).into());
+        }
+        let stdout = String::from_utf8(output.stdout)?;
+        // like duct's read(), trailing newlines are trimmed
+        Ok(stdout.trim_end_matches(['\r', '\n']).to_string())
+    }
+
+    /// runs the script with its stdout and stderr captured, the status is not checked
+    pub fn capture(&self, settings: &Settings, script: &Script) -> Result<Output> {
+        let cmd = self.cmd(settings, script).stdout_capture().stderr_capture();
+        self.output(settings, script, cmd)
     }
 
     pub fn run_by_line(
@@ -158,12 +280,32 @@ impl ScriptManager {
         settings: &Settings,
         script: &Script,
         pr: &ProgressReport,
+    ) -> Result<()> {
+        self.retry(
+            settings,
+            script,
+            || self.run_by_line_once(settings, script, pr),
+            |result| result.is_ok(),
+        )
+    }
+
+    fn run_by_line_once(
+        &self,
+        settings: &Settings,
+        script: &Script,
+        pr: &ProgressReport,
     ) -> Result<()> {
         let mut cmd = CmdLineRunner::new(settings, self.get_script_path(script));
-        cmd.with_pr(pr).env_clear().envs(&self.env);
+        cmd.with_pr(pr)
+            .with_timeout(self.timeout(settings, script))
+            .env_clear()
+            .envs(self.env(settings));
         if let Err(e) = cmd.execute() {
             let status = match e.downcast_ref::<Error>() {
                 Some(ScriptFailed(_, status)) => *status,
+                Some(ScriptTimeout(_, _, timeout)) => {
+                    return Err(self.timeout_error(script, *timeout).into());
+                }
                 _ => None,
             };
             let path = display_path(&self.get_script_path(script));
@@ -171,4 +313,229 @@ impl ScriptManager {
         }
         Ok(())
     }
+
+    /// runs the script, trying again up to plugin_script_retries times until it succeeds
+    fn output(&self, settings: &Settings, script: &Script, cmd: Expression) -> Result<Output> {
+        self.retry(
+            settings,
+            script,
+            || self.wait(settings, script, &cmd),
+            |result| result.as_ref().map_or(false, |o| o.status.success()),
+        )
+    }
+
+    /// waits for the script to finish, it is killed along with its subprocesses if it runs longer
+    /// than its timeout. raw scripts stay in rtx's process group so they can read from the terminal
+    fn wait(&self, settings: &Settings, script: &Script, cmd: &Expression) -> Result<Output> {
+        let timeout = self.timeout(settings, script);
+        let process_group = timeout.is_some() && !settings.raw;
+        let mut cmd = cmd.unchecked();
+        if process_group {
+            cmd = cmd.before_spawn(|cmd| {
+                cmd.process_group(0);
+                Ok(())
+            });
+        }
+        let handle = cmd
+            .start()
+            .with_context(|| ScriptFailed(display_path(&self.get_script_path(script)), None))?;
+        if let Some(timeout) = timeout {
+            let group = process_group.then(|| ProcessGroup::new(handle.pids()[0]));
+            let start = Instant::now();
+            while handle.try_wait()?.is_none() {
+                if start.elapsed() >= timeout {
+                    if let Some(group) = group {
+                        group.kill()?;
+                    }
+                    handle.kill()?;
+                    return Err(self.timeout_error(script, timeout).into());
+                }
+                thread::sleep(Duration::from_millis(10));
+            }
+        }
+        Ok(handle.into_output()?)
+    }
+
+    fn retry<T>(
+        &self,
+        settings: &Settings,
+        script: &Script,
+        run: impl Fn() -> Result<T>,
+        succeeded: impl Fn(&Result<T>) -> bool,
+    ) -> Result<T> {
+        let retries = match script.is_retryable() {
+            true => self.retries(settings, script),
+            false => 0,
+        };
+        for attempt in 1..=retries {
+            let result = run();
+            if succeeded(&result) {
+                return result;
+            }
+            warn!(
+                "[{}] {} failed, retrying ({attempt}/{retries})",
+                self.plugin_name, script
+            );
+            self.clean_for_retry(script)?;
+        }
+        run()
+    }
+
+    /// download and install start over in an empty directory so a retry doesn't see the files
+    /// left behind by the attempt that failed
+    fn clean_for_retry(&self, script: &Script) -> Result<()> {
+        let key = match script {
+            Script::Download => "ASDF_DOWNLOAD_PATH",
+            Script::Install => "ASDF_INSTALL_PATH",
+            _ => return Ok(()),
+        };
+        if let Some(path) = self.env.get(OsStr::new(key)) {
+            file::remove_all(path)?;
+            file::create_dir_all(path)?;
+        }
+        Ok(())
+    }
+
+    fn timeout_error(&self, script: &Script, timeout: Duration) -> Error {
+        ScriptTimeout(script.to_string(), Some(self.plugin_name.clone()), timeout)
+    }
+
+    /// runs a user-defined hook from the global config
+    pub fn run_hook(
+        &self,
+        settings: &Settings,
+        hook: &str,
+        command: &str,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        let mut cmd = CmdLineRunner::new(settings, "sh");
+        cmd.with_pr(pr)
+            .env_clear()
+            .envs(self.env(settings))
+            .arg("-c")
+            .arg(command);
+        if let Err(e) = cmd.execute() {
+            let status = match e.downcast_ref::<Error>() {
+                Some(ScriptFailed(_, status)) => *status,
+                _ => None,
+            };
+            return Err(ScriptFailed(hook.to_string(), status).into());
+        }
+        Ok(())
+    }
+}
+
+fn is_env_allowed(settings: &Settings, key: &str) -> bool {
+    let allowlist = &settings.plugin_script_env_allowlist;
+    let denylist = &settings.plugin_script_env_denylist;
+    (allowlist.is_empty() || allowlist.iter().any(|p| env_matches(p, key)))
+        && !denylist.iter().any(|p| env_matches(p, key))
+}
+
+/// patterns can end with "*" to match a prefix, e.g.: "AWS_*"
+fn env_matches(pattern: &str, key: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => key.starts_with(prefix),
+        None => key == pattern,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::plugins::rtx_plugin_toml::RtxPluginTomlScriptLimits;
+
+    use super::*;
+
+    #[test]
+    fn test_is_env_allowed() {
+        let settings = Settings {
+            plugin_script_env_allowlist: vec!["HOME".into(), "AWS_*".into()],
+            plugin_script_env_denylist: vec!["AWS_SECRET_ACCESS_KEY".into()],
+            ..Settings::default()
+        };
+        assert!(is_env_allowed(&settings, "HOME"));
+        assert!(is_env_allowed(&settings, "AWS_REGION"));
+        assert!(!is_env_allowed(&settings, "AWS_SECRET_ACCESS_KEY"));
+        assert!(!is_env_allowed(&settings, "GITHUB_TOKEN"));
+        assert!(is_env_allowed(&Settings::default(), "GITHUB_TOKEN"));
+    }
+
+    #[test]
+    fn test_script_timeout() {
+        let plugin_path = dirs::CACHE.join("test-script-timeout");
+        let script = plugin_path.join("bin/list-all");
+        fs::create_dir_all(script.parent().unwrap()).unwrap();
+        fs::write(&script, "#!/usr/bin/env bash\nsleep 10\n").unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        let settings = Settings {
+            plugin_script_timeout: Duration::from_secs(60),
+            ..Settings::default()
+        };
+        let limits = RtxPluginTomlScripts {
+            scripts: [(
+                "list-all".to_string(),
+                RtxPluginTomlScriptLimits {
+                    timeout: Some(Duration::from_millis(100)),
+                    retries: None,
+                },
+            )]
+            .into(),
+            ..Default::default()
+        };
+        let sm = ScriptManager::new(plugin_path.clone()).with_limits(limits);
+
+        let err = sm.read(&settings, &Script::ListAll, false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "[test-script-timeout] list-all timed out after 100ms"
+        );
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(ScriptTimeout(_, Some(_), _))
+        ));
+        fs::remove_dir_all(plugin_path).unwrap();
+    }
+
+    #[test]
+    fn test_script_retry_cleans_install_path() {
+        let plugin_path = dirs::CACHE.join("test-script-retry");
+        let install_path = plugin_path.join("installs/1.0.0");
+        let script = plugin_path.join("bin/install");
+        fs::create_dir_all(script.parent().unwrap()).unwrap();
+        fs::create_dir_all(&install_path).unwrap();
+        // fails the first time after leaving a file behind, which fails the next attempt if it
+        // is still there
+        fs::write(
+            &script,
+            indoc::indoc! {r#"
+                #!/usr/bin/env bash
+                [ -f "$ASDF_INSTALL_PATH/partial" ] && exit 2
+                touch "$ASDF_INSTALL_PATH/partial"
+                [ -f "$ASDF_INSTALL_PATH/../attempted" ] && exit 0
+                touch "$ASDF_INSTALL_PATH/../attempted"
+                exit 1
+            "#},
+        )
+        .unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        let limits = RtxPluginTomlScripts {
+            default: RtxPluginTomlScriptLimits {
+                timeout: None,
+                retries: Some(1),
+            },
+            ..Default::default()
+        };
+        let sm = ScriptManager::new(plugin_path.clone())
+            .with_limits(limits)
+            .with_env("ASDF_INSTALL_PATH", install_path.clone());
+
+        sm.run(&Settings::default(), &Script::Install).unwrap();
+        assert!(install_path.join("partial").exists());
+        fs::remove_dir_all(plugin_path).unwrap();
+    }
 }
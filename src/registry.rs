@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use once_cell::sync::Lazy;
+use serde_derive::Deserialize;
+
+use crate::dirs;
+use crate::file::display_path;
+
+/// the newest registry format this version of rtx can read
+pub const REGISTRY_VERSION: u32 = 1;
+
+/// the registry bundled with rtx, used until `rtx plugins registry update` downloads a newer one
+const BUNDLED_REGISTRY: &str = include_str!("../registry.toml");
+
+pub static REGISTRY: Lazy<Registry> = Lazy::new(Registry::load);
+
+/// where `rtx plugins registry update` writes the registry
+pub fn registry_path() -> PathBuf {
+    dirs::ROOT.join("registry.toml")
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Registry {
+    pub version: u32,
+    #[serde(default)]
+    pub plugins: BTreeMap<String, RegistryPlugin>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RegistryPlugin {
+    /// a git url for asdf plugins or the package name for other backends
+    pub repo: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default)]
+    pub backend: RegistryBackend,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RegistryBackend {
+    #[default]
+    Asdf,
+    Cargo,
+    Github,
+    Go,
+    Npm,
+    Pipx,
+}
+
+impl Registry {
+    pub fn parse(body: &str) -> Result<Self> {
+        let registry: Self = toml::from_str(body)?;
+        if registry.version > REGISTRY_VERSION {
+            return Err(eyre!(
+                "registry version {} is not supported by this version of rtx, upgrade rtx to use it",
+                registry.version
+            ));
+        }
+        Ok(registry)
+    }
+
+    /// a downloaded registry takes precedence over the bundled one unless it cannot be read
+    fn load() -> Self {
+        let path = registry_path();
+        if path.exists() {
+            let registry = fs::read_to_string(&path)
+                .map_err(|err| eyre!(err))
+                .and_then(|body| Self::parse(&body))
+                .wrap_err_with(|| format!("failed to read registry {}", display_path(&path)));
+            match registry {
+                Ok(registry) => return registry,
+                Err(err) => warn!("{:#}", err),
+            }
+        }
+        Self::parse(BUNDLED_REGISTRY).unwrap()
+    }
+
+    /// repo urls of the asdf plugins by name and alias, other backends are not shorthands since
+    /// they are used with their prefix, e.g.: "cargo:ripgrep"
+    pub fn shorthands(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.plugins
+            .iter()
+            .filter(|(_, p)| p.backend == RegistryBackend::Asdf)
+            .flat_map(|(name, p)| {
+                let aliases = p.aliases.iter().map(|a| a.as_str());
+                std::iter::once(name.as_str())
+                    .chain(aliases)
+                    .map(|name| (name, p.repo.as_str()))
+            })
+    }
+
+    /// finds a plugin by its name or one of its aliases
+    pub fn get(&self, name: &str) -> Option<&RegistryPlugin> {
+        self.plugins.get(name).or_else(|| {
+            self.plugins
+                .values()
+                .find(|p| p.aliases.iter().any(|a| a == name))
+        })
+    }
+}
+
+impl RegistryPlugin {
+    /// the name to use this plugin with in rtx, e.g.: "node" or "cargo:ripgrep"
+    pub fn tool_name(&self, name: &str) -> String {
+        match self.backend {
+            RegistryBackend::Asdf => name.to_string(),
+            RegistryBackend::Cargo => format!("cargo:{}", self.repo),
+            RegistryBackend::Github => format!("github:{}", self.repo),
+            RegistryBackend::Go => format!("go:{}", self.repo),
+            RegistryBackend::Npm => format!("npm:{}", self.repo),
+            RegistryBackend::Pipx => format!("pipx:{}", self.repo),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_bundled_registry() {
+        let registry = Registry::parse(BUNDLED_REGISTRY).unwrap();
+        let shorthands: BTreeMap<_, _> = registry.shorthands().collect();
+        assert_eq!(
+            shorthands["nodejs"],
+            "https://github.com/rtx-plugins/rtx-nodejs.git"
+        );
+        assert_eq!(shorthands["node"], shorthands["nodejs"]);
+        assert_eq!(
+            registry.get("golang").unwrap().description.as_deref(),
+            Some("Go programming language")
+        );
+    }
+
+    #[test]
+    fn test_registry_backends() {
+        let registry = Registry::parse(indoc! {r#"
+            version = 1
+            [plugins]
+            rg = {repo = "ripgrep", backend = "cargo"}
+            tiny = {repo = "https://github.com/rtx-plugins/rtx-tiny.git"}
+        "#})
+        .unwrap();
+        assert_eq!(registry.plugins["rg"].tool_name("rg"), "cargo:ripgrep");
+        assert_eq!(registry.plugins["tiny"].tool_name("tiny"), "tiny");
+        assert_eq!(
+            registry.shorthands().map(|(n, _)| n).collect::<Vec<_>>(),
+            ["tiny"]
+        );
+    }
+
+    #[test]
+    fn test_registry_newer_version() {
+        let err = Registry::parse("version = 2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "registry version 2 is not supported by this version of rtx, upgrade rtx to use it"
+        );
+    }
+}
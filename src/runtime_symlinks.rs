@@ -8,7 +8,6 @@ use regex::Regex;
 use versions::Version;
 
 use crate::config::Config;
-use crate::dirs;
 use crate::file::make_symlink;
 use crate::tool::Tool;
 
@@ -16,7 +15,7 @@ pub fn rebuild_symlinks(config: &Config) -> Result<()> {
     for plugin in config.tools.values() {
         remove_existing_symlinks(plugin)?;
         let symlinks = list_symlinks(config, plugin)?;
-        let installs_dir = dirs::INSTALLS.join(&plugin.name);
+        let installs_dir = &plugin.installs_path;
         for (from, to) in symlinks {
             let from = installs_dir.join(from);
             if from.exists() {
@@ -77,7 +76,7 @@ fn installed_versions(plugin: &Tool) -> Result<Vec<String>> {
 }
 
 fn remove_existing_symlinks(plugin: &Tool) -> Result<()> {
-    let installs_dir = dirs::INSTALLS.join(&plugin.name);
+    let installs_dir = &plugin.installs_path;
     if !installs_dir.exists() {
         return Ok(());
     }
@@ -6,8 +6,8 @@ use color_eyre::eyre::Result;
 use toml::Table;
 
 use crate::config::Settings;
-use crate::default_shorthands::DEFAULT_SHORTHANDS;
 use crate::dirs;
+use crate::registry::REGISTRY;
 
 pub type Shorthands = HashMap<String, String>;
 
@@ -15,8 +15,8 @@ pub fn get_shorthands(settings: &Settings) -> Shorthands {
     let mut shorthands = HashMap::new();
     if !settings.disable_default_shorthands {
         shorthands.extend(
-            DEFAULT_SHORTHANDS
-                .iter()
+            REGISTRY
+                .shorthands()
                 .map(|(k, v)| (k.to_string(), v.to_string())),
         );
     };
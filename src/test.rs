@@ -25,6 +25,10 @@ fn init() {
     env::set_var("RTX_DEFAULT_TOOL_VERSIONS_FILENAME", ".test-tool-versions");
     env::set_var("RTX_DEFAULT_CONFIG_FILENAME", ".test.rtx.toml");
     env::set_var("RTX_MISSING_RUNTIME_BEHAVIOR", "autoinstall");
+    env::set_var(
+        "RTX_NODE_MIRROR_URL",
+        format!("file://{}", env::HOME.join("fixtures/node").display()),
+    );
     //env::set_var("TERM", "dumb");
     reset_config();
     assert_cli!("trust");
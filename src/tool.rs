@@ -1,6 +1,8 @@
 use std::collections::{BTreeMap, HashMap};
+use std::fs;
 use std::fs::{remove_file, File};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use color_eyre::eyre::{eyre, Result};
 use console::style;
@@ -8,10 +10,14 @@ use itertools::Itertools;
 use regex::Regex;
 use versions::Versioning;
 
-use crate::config::{Config, Settings};
+use crate::config::{lockfile, Config, Settings};
 use crate::file::{create_dir_all, display_path, remove_all_with_warning};
+use crate::git::Git;
 use crate::lock_file::LockFile;
-use crate::plugins::{ExternalPlugin, Plugin, PluginType};
+use crate::plugins::{
+    plugin_dirname, vendored_plugin_dirname, ExternalPlugin, Plugin, PluginType, PluginUpdate,
+    Script, ScriptManager,
+};
 use crate::runtime_symlinks::is_runtime_symlink;
 use crate::toolset::{ToolVersion, ToolVersionRequest};
 use crate::ui::progress_report::{ProgressReport, PROG_TEMPLATE};
@@ -21,20 +27,36 @@ use crate::{dirs, file};
 pub struct Tool {
     pub name: String,
     pub plugin: Box<dyn Plugin>,
+    /// the name of the plugin's directories in installs, downloads and cache
+    pub dirname: String,
     pub installs_path: PathBuf,
     pub plugin_path: PathBuf,
 }
 
 impl Tool {
     pub fn new(name: String, plugin: Box<dyn Plugin>) -> Self {
+        let dirname = plugin_dirname(&name);
         Self {
-            installs_path: dirs::INSTALLS.join(&name),
-            plugin_path: dirs::PLUGINS.join(&name),
+            installs_path: dirs::INSTALLS.join(&dirname),
+            plugin_path: dirs::PLUGINS.join(&dirname),
+            dirname,
             name,
             plugin,
         }
     }
 
+    /// a plugin vendored in a project with `[plugins] name = { path = "..." }`
+    pub fn vendored(name: String, plugin_path: PathBuf) -> Self {
+        let plugin = ExternalPlugin::from_path(&name, plugin_path.clone());
+        let dirname = vendored_plugin_dirname(&name, &plugin_path);
+        Self {
+            installs_path: dirs::INSTALLS.join(&dirname),
+            dirname,
+            plugin_path,
+            ..Self::new(name, Box::new(plugin))
+        }
+    }
+
     pub fn list() -> Result<Vec<Self>> {
         Ok(file::dir_subdirs(&dirs::PLUGINS)?
             .iter()
@@ -49,6 +71,10 @@ impl Tool {
         self.plugin.is_installed()
     }
 
+    pub fn is_vendored(&self) -> bool {
+        !self.plugin_path.starts_with(&*dirs::PLUGINS)
+    }
+
     pub fn get_remote_url(&self) -> Option<String> {
         self.plugin.get_remote_url()
     }
@@ -82,44 +108,57 @@ impl Tool {
         Ok(versions)
     }
 
-    pub fn list_remote_versions(&self, settings: &Settings) -> Result<Vec<String>> {
-        self.plugin.list_remote_versions(settings)
+    pub fn list_remote_versions(&self, config: &Config) -> Result<Vec<String>> {
+        self.plugin.list_remote_versions(config)
     }
 
-    pub fn list_versions_matching(&self, settings: &Settings, query: &str) -> Result<Vec<String>> {
+    /// prereleases are only included with the `prerelease` setting or if the query is one
+    pub fn list_versions_matching(
+        &self,
+        config: &Config,
+        settings: &Settings,
+        query: &str,
+    ) -> Result<Vec<String>> {
         let mut query = query;
         if query == "latest" {
             query = "[0-9]";
         }
-        let version_regex = regex!(
-            r"(^Available versions:|-src|-dev|-latest|-stm|[-\\.]rc|-milestone|-alpha|-beta|[-\\.]pre|-next|(a|b|c)[0-9]+|snapshot|master)"
-        );
+        let prerelease = settings.prerelease || self.is_prerelease(query);
         let query_regex =
             Regex::new((String::from(r"^\s*") + query).as_str()).expect("error parsing regex");
         let versions = self
-            .list_remote_versions(settings)?
+            .list_remote_versions(config)?
             .into_iter()
-            .filter(|v| !version_regex.is_match(v))
+            .filter(|v| !v.starts_with("Available versions:"))
+            .filter(|v| prerelease || !self.is_prerelease(v))
             .filter(|v| query_regex.is_match(v))
             .collect();
         Ok(versions)
     }
 
+    pub fn is_prerelease(&self, version: &str) -> bool {
+        self.plugin.is_prerelease(version)
+    }
+
     pub fn latest_version(
         &self,
+        config: &Config,
         settings: &Settings,
         query: Option<String>,
     ) -> Result<Option<String>> {
         match query {
             Some(query) => {
-                let matches = self.list_versions_matching(settings, &query)?;
+                let matches = self.list_versions_matching(config, settings, &query)?;
                 let v = match matches.contains(&query) {
                     true => Some(query),
                     false => matches.last().map(|v| v.to_string()),
                 };
                 Ok(v)
             }
-            None => self.latest_stable_version(settings),
+            None if settings.prerelease => {
+                self.latest_version(config, settings, Some("latest".into()))
+            }
+            None => self.latest_stable_version(config, settings),
         }
     }
 
@@ -142,15 +181,23 @@ impl Tool {
         self.plugin.get_aliases(settings)
     }
 
+    pub fn get_alias(&self, settings: &Settings, alias: &str) -> Result<Option<String>> {
+        self.plugin.get_alias(settings, alias)
+    }
+
     pub fn legacy_filenames(&self, settings: &Settings) -> Result<Vec<String>> {
         self.plugin.legacy_filenames(settings)
     }
 
-    fn latest_stable_version(&self, settings: &Settings) -> Result<Option<String>> {
+    fn latest_stable_version(
+        &self,
+        config: &Config,
+        settings: &Settings,
+    ) -> Result<Option<String>> {
         if let Some(latest) = self.plugin.latest_stable_version(settings)? {
             Ok(Some(latest))
         } else {
-            self.latest_version(settings, Some("latest".into()))
+            self.latest_version(config, settings, Some("latest".into()))
         }
     }
 
@@ -189,7 +236,12 @@ impl Tool {
         let _lock = self.get_lock(&tv.install_path(), force)?;
         self.create_install_dirs(tv)?;
 
-        if let Err(e) = self.plugin.install_version(config, tv, pr) {
+        let install = || {
+            self.run_hooks(config, tv, &Script::PreInstall, pr)?;
+            self.plugin.install_version(config, tv, pr)?;
+            self.run_hooks(config, tv, &Script::PostInstall, pr)
+        };
+        if let Err(e) = install() {
             self.cleanup_install_dirs_on_error(&config.settings, tv);
             return Err(e);
         }
@@ -221,6 +273,7 @@ impl Tool {
         pr.set_message(format!("uninstall {tv}"));
 
         if !dryrun {
+            self.run_hooks(config, tv, &Script::PreUninstall, pr)?;
             self.plugin.uninstall_version(config, tv)?;
         }
         let rmdir = |dir: &Path| {
@@ -235,6 +288,29 @@ impl Tool {
         };
         rmdir(&tv.install_path())?;
         rmdir(&tv.download_path())?;
+        if !dryrun {
+            self.run_hooks(config, tv, &Script::PostUninstall, pr)?;
+        }
+        Ok(())
+    }
+
+    /// runs the plugin's hook script, e.g.: bin/post-install, then the user's hook of the same
+    /// name from the global config, e.g.: `post_install_node = "npm i -g yarn"`
+    fn run_hooks(
+        &self,
+        config: &Config,
+        tv: &ToolVersion,
+        hook: &Script,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        self.plugin.run_hook(config, tv, hook, pr)?;
+        let name = format!("{}_{}", hook.to_string().replace('-', "_"), self.name);
+        if let Some(command) = config.global_config.hooks().get(&name) {
+            pr.set_message(name.clone());
+            ScriptManager::new(self.plugin_path.clone())
+                .with_tv_env(config, tv)
+                .run_hook(&config.settings, &name, command, pr)?;
+        }
         Ok(())
     }
 
@@ -244,10 +320,83 @@ impl Tool {
         }
         self.decorate_progress_bar(pr, None);
         let _lock = self.get_lock(&self.plugin_path, force)?;
-        self.plugin.install(config, pr)
+        self.plugin.install(config, pr)?;
+        self.record_update("installed");
+        Ok(())
     }
-    pub fn update(&self, git_ref: Option<String>) -> Result<()> {
-        self.plugin.update(git_ref)
+    pub fn update(
+        &self,
+        pr: &mut ProgressReport,
+        git_ref: Option<String>,
+        dry_run: bool,
+    ) -> Result<Option<PluginUpdate>> {
+        self.decorate_progress_bar(pr, None);
+        let _lock = self.get_lock(&self.plugin_path, false)?;
+        let update = self.plugin.update(pr, git_ref, dry_run);
+        if !dry_run {
+            match &update {
+                Ok(Some(u)) if u.is_up_to_date() => self.record_update("up to date"),
+                Ok(Some(u)) => self.record_update(&format!("updated {}", u.short_revs())),
+                Ok(None) => {}
+                Err(err) => self.record_update(&format!("failed: {err:#}")),
+            }
+        }
+        update
+    }
+
+    /// fetches updates for the plugin in the background if it was last updated longer than
+    /// plugin_autoupdate_last_check_duration ago, errors are only recorded for `rtx doctor`
+    /// plugins pinned to a ref or locked in a `.rtx.lock` are left alone
+    pub fn autoupdate(&self, config: &Config) {
+        let interval = config.settings.plugin_autoupdate_last_check_duration;
+        if interval.is_zero()
+            || !matches!(self.plugin.get_type(), PluginType::External)
+            || self.is_vendored()
+            || self.plugin_path.is_symlink()
+            || !Git::new(self.plugin_path.clone()).is_repo()
+        {
+            return;
+        }
+        if matches!(self.last_update(), Some((age, _)) if age < interval) {
+            return;
+        }
+        if self.is_pinned() || lockfile::is_locked(config, &self.name) {
+            return;
+        }
+        // recorded first so other rtx processes do not start updating the plugin too
+        self.record_update("checking for updates");
+        if let Err(err) = spawn_autoupdate(&self.name) {
+            self.record_update(&format!("failed: {err:#}"));
+        }
+    }
+
+    /// plugins installed from `url#ref` are not on the default branch, updating would move them
+    fn is_pinned(&self) -> bool {
+        let git = Git::new(self.plugin_path.clone());
+        match (git.current_branch(), git.remote_default_branch()) {
+            (Some(branch), Ok(default_branch)) => branch != default_branch,
+            _ => true,
+        }
+    }
+
+    /// how long ago the plugin was installed or updated and the result of that
+    pub fn last_update(&self) -> Option<(Duration, String)> {
+        let path = self.last_update_path();
+        let age = file::modified_duration(&path).ok()?;
+        let status = fs::read_to_string(&path).ok()?;
+        Some((age, status))
+    }
+
+    fn record_update(&self, status: &str) {
+        let path = self.last_update_path();
+        let result = create_dir_all(path.parent().unwrap()).and_then(|_| fs::write(&path, status));
+        if let Err(err) = result {
+            debug!("failed to write {}: {:#}", display_path(&path), err);
+        }
+    }
+
+    fn last_update_path(&self) -> PathBuf {
+        dirs::CACHE.join(&self.dirname).join("last_update")
     }
     pub fn uninstall(&self, pr: &ProgressReport) -> Result<()> {
         self.plugin.uninstall(pr)
@@ -331,6 +480,26 @@ impl Tool {
     }
 }
 
+#[cfg(not(test))]
+fn spawn_autoupdate(name: &str) -> Result<()> {
+    use std::process::{Command, Stdio};
+
+    debug!("updating plugin {} in the background", name);
+    Command::new(&*crate::env::RTX_EXE)
+        .args(["plugins", "update", name])
+        .current_dir(&*dirs::ROOT)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    Ok(())
+}
+
+#[cfg(test)]
+fn spawn_autoupdate(_name: &str) -> Result<()> {
+    Ok(())
+}
+
 impl PartialEq for Tool {
     fn eq(&self, other: &Self) -> bool {
         self.plugin_path == other.plugin_path
@@ -36,13 +36,7 @@ impl ToolsetBuilder {
     }
 
     pub fn build(self, config: &mut Config) -> Result<Toolset> {
-        let mut toolset = Toolset {
-            latest_versions: self.latest_versions,
-            ..Default::default()
-        };
-        load_config_files(config, &mut toolset);
-        load_runtime_env(&mut toolset, env::vars().collect());
-        load_runtime_args(&mut toolset, &self.args);
+        let mut toolset = self.build_unresolved(config)?;
         toolset.resolve(config);
 
         if self.install_missing {
@@ -53,6 +47,18 @@ impl ToolsetBuilder {
         debug!("{}", toolset);
         Ok(toolset)
     }
+
+    /// loads the requested versions without resolving them or installing plugins
+    pub fn build_unresolved(&self, config: &Config) -> Result<Toolset> {
+        let mut toolset = Toolset {
+            latest_versions: self.latest_versions,
+            ..Default::default()
+        };
+        load_config_files(config, &mut toolset);
+        load_runtime_env(&mut toolset, env::vars().collect())?;
+        load_runtime_args(&mut toolset, &self.args);
+        Ok(toolset)
+    }
 }
 
 fn load_config_files(config: &Config, ts: &mut Toolset) {
@@ -61,7 +67,7 @@ fn load_config_files(config: &Config, ts: &mut Toolset) {
     }
 }
 
-fn load_runtime_env(ts: &mut Toolset, env: BTreeMap<String, String>) {
+fn load_runtime_env(ts: &mut Toolset, env: BTreeMap<String, String>) -> Result<()> {
     for (k, v) in env {
         if k.starts_with("RTX_") && k.ends_with("_VERSION") {
             let plugin_name = k[4..k.len() - 8].to_lowercase();
@@ -72,12 +78,13 @@ fn load_runtime_env(ts: &mut Toolset, env: BTreeMap<String, String>) {
             let source = ToolSource::Environment(k, v.clone());
             let mut env_ts = Toolset::new(source);
             for v in v.split_whitespace() {
-                let tvr = ToolVersionRequest::new(plugin_name.clone(), v);
+                let tvr = ToolVersionRequest::new(plugin_name.clone(), v)?;
                 env_ts.add_version(tvr, Default::default());
             }
             ts.merge(&env_ts);
         }
     }
+    Ok(())
 }
 
 fn load_runtime_args(ts: &mut Toolset, args: &[ToolArg]) {
@@ -18,6 +18,7 @@ pub use tool_source::ToolSource;
 pub use tool_version::ToolVersion;
 pub use tool_version_list::ToolVersionList;
 pub use tool_version_request::ToolVersionRequest;
+pub use version_range::VersionRange;
 
 use crate::config::{Config, MissingRuntimeBehavior};
 use crate::env;
@@ -32,6 +33,7 @@ mod tool_source;
 mod tool_version;
 mod tool_version_list;
 mod tool_version_request;
+mod version_range;
 
 pub type ToolVersionOptions = BTreeMap<String, String>;
 
@@ -240,17 +242,23 @@ impl Toolset {
             .into_par_iter()
             .map(|p| {
                 let versions = p.list_installed_versions()?;
-                Ok(versions.into_iter().map(|v| {
-                    match current_versions.get(&(p.name.clone(), v.clone())) {
-                        Some((p, tv)) => (p.clone(), tv.clone()),
-                        None => {
-                            let tv = ToolVersionRequest::new(p.name.clone(), &v)
-                                .resolve(config, p, Default::default(), false)
-                                .unwrap();
-                            (p.clone(), tv)
-                        }
-                    }
-                }))
+                versions
+                    .into_iter()
+                    .map(
+                        |v| match current_versions.get(&(p.name.clone(), v.clone())) {
+                            Some((p, tv)) => Ok((p.clone(), tv.clone())),
+                            None => {
+                                let tv = ToolVersionRequest::new(p.name.clone(), &v)?.resolve(
+                                    config,
+                                    p,
+                                    Default::default(),
+                                    false,
+                                )?;
+                                Ok((p.clone(), tv))
+                            }
+                        },
+                    )
+                    .collect::<Result<Vec<_>>>()
             })
             .collect::<Result<Vec<_>>>()?
             .into_iter()
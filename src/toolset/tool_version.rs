@@ -2,15 +2,17 @@ use std::fmt::{Display, Formatter};
 use std::fs;
 use std::path::PathBuf;
 
-use color_eyre::eyre::Result;
-use versions::{Chunk, Version};
+use color_eyre::eyre::{eyre, Result};
+use itertools::Itertools;
+use versions::{Chunk, Version, Versioning};
 
-use crate::config::Config;
+use crate::config::{Config, Settings};
 use crate::dirs;
+use crate::errors::Error::VersionNotFound;
 use crate::hash::hash_to_str;
 use crate::plugins::PluginName;
 use crate::tool::Tool;
-use crate::toolset::{ToolVersionOptions, ToolVersionRequest};
+use crate::toolset::{ToolVersionOptions, ToolVersionRequest, VersionRange};
 
 /// represents a single version of a tool for a particular plugin
 #[derive(Debug, Clone)]
@@ -19,6 +21,8 @@ pub struct ToolVersion {
     pub plugin_name: PluginName,
     pub version: String,
     pub opts: ToolVersionOptions,
+    /// the name of the plugin's directories in installs, downloads and cache
+    dirname: String,
 }
 
 impl ToolVersion {
@@ -30,6 +34,7 @@ impl ToolVersion {
     ) -> Self {
         ToolVersion {
             plugin_name: tool.name.to_string(),
+            dirname: tool.dirname.clone(),
             version,
             request,
             opts,
@@ -43,6 +48,10 @@ impl ToolVersion {
         opts: ToolVersionOptions,
         latest_versions: bool,
     ) -> Result<Self> {
+        if !tool.plugin.resolves_remote_versions(&opts) {
+            let version = request.version();
+            return Ok(Self::new(tool, request, opts, version));
+        }
         let tv = match request.clone() {
             ToolVersionRequest::Version(_, v) => {
                 Self::resolve_version(config, tool, request, latest_versions, &v, opts)?
@@ -50,6 +59,12 @@ impl ToolVersion {
             ToolVersionRequest::Prefix(_, prefix) => {
                 Self::resolve_prefix(config, tool, request, &prefix, opts)?
             }
+            ToolVersionRequest::Range(_, range) => {
+                Self::resolve_range(config, tool, request, latest_versions, &range, opts)?
+            }
+            ToolVersionRequest::Sub {
+                sub, orig_version, ..
+            } => Self::resolve_sub(config, tool, request, &sub, &orig_version, opts)?,
             _ => {
                 let version = request.version();
                 Self::new(tool, request, opts, version)
@@ -63,20 +78,20 @@ impl ToolVersion {
             ToolVersionRequest::Path(_, p) => p.to_string_lossy().to_string(),
             _ => self.tv_pathname(),
         };
-        dirs::INSTALLS.join(&self.plugin_name).join(pathname)
+        dirs::INSTALLS.join(&self.dirname).join(pathname)
     }
     pub fn cache_path(&self) -> PathBuf {
-        dirs::CACHE.join(&self.plugin_name).join(self.tv_pathname())
+        dirs::CACHE.join(&self.dirname).join(self.tv_pathname())
     }
     pub fn download_path(&self) -> PathBuf {
-        dirs::DOWNLOADS
-            .join(&self.plugin_name)
-            .join(self.tv_pathname())
+        dirs::DOWNLOADS.join(&self.dirname).join(self.tv_pathname())
     }
     fn tv_pathname(&self) -> String {
         match &self.request {
             ToolVersionRequest::Version(_, _) => self.version.to_string(),
             ToolVersionRequest::Prefix(_, _) => self.version.to_string(),
+            ToolVersionRequest::Range(_, _) => self.version.to_string(),
+            ToolVersionRequest::Sub { .. } => self.version.to_string(),
             ToolVersionRequest::Ref(_, r) => format!("ref-{}", r),
             ToolVersionRequest::Path(_, p) => format!("path-{}", hash_to_str(p)),
             ToolVersionRequest::System(_) => "system".to_string(),
@@ -92,6 +107,9 @@ impl ToolVersion {
         opts: ToolVersionOptions,
     ) -> Result<ToolVersion> {
         let v = config.resolve_alias(&tool.name, v)?;
+        if let Some((orig_version, sub)) = v.split_once("!-") {
+            return Self::resolve_sub(config, tool, request, sub, orig_version, opts);
+        }
         match v.split_once(':') {
             Some(("ref", r)) => {
                 return Ok(Self::resolve_ref(tool, r.to_string(), opts));
@@ -102,9 +120,16 @@ impl ToolVersion {
             Some(("prefix", p)) => {
                 return Self::resolve_prefix(config, tool, request, p, opts);
             }
+            Some((p, v)) if p.starts_with("sub-") => {
+                return Self::resolve_sub(config, tool, request, &p[4..], v, opts);
+            }
+            _ if VersionRange::is_range(&v) => {
+                return Self::resolve_range(config, tool, request, latest_versions, &v, opts);
+            }
             _ => (),
         }
 
+        let settings = tool_settings(config, &opts);
         let build = |v| Ok(Self::new(tool, request.clone(), opts.clone(), v));
 
         if !tool.is_installed() {
@@ -122,7 +147,7 @@ impl ToolVersion {
                     return build(v);
                 }
             }
-            if let Some(v) = tool.latest_version(&config.settings, None)? {
+            if let Some(v) = tool.latest_version(config, &settings, None)? {
                 return build(v);
             }
         }
@@ -132,38 +157,13 @@ impl ToolVersion {
                 return build(v);
             }
         }
-        let matches = tool.list_versions_matching(&config.settings, &v)?;
+        let matches = tool.list_versions_matching(config, &settings, &v)?;
         if matches.contains(&v) {
             return build(v);
         }
-        if v.contains("!-") {
-            if let Some(tv) = Self::resolve_bang(config, tool, request.clone(), &v, &opts)? {
-                return Ok(tv);
-            }
-        }
         Self::resolve_prefix(config, tool, request, &v, opts)
     }
 
-    /// resolve a version like `12.0.0!-1` which becomes `11.0.0`, `12.1.0!-0.1` becomes `12.0.0`
-    fn resolve_bang(
-        config: &Config,
-        tool: &Tool,
-        request: ToolVersionRequest,
-        v: &str,
-        opts: &ToolVersionOptions,
-    ) -> Result<Option<Self>> {
-        let (wanted, minus) = v.split_once("!-").unwrap();
-        let wanted = match wanted {
-            "latest" => tool.latest_version(&config.settings, None)?.unwrap(),
-            _ => config.resolve_alias(&tool.name, wanted)?,
-        };
-        let wanted = version_sub(&wanted, minus);
-        let tv = tool
-            .latest_version(&config.settings, Some(wanted))?
-            .map(|v| Self::new(tool, request, opts.clone(), v));
-        Ok(tv)
-    }
-
     fn resolve_prefix(
         config: &Config,
         tool: &Tool,
@@ -171,7 +171,8 @@ impl ToolVersion {
         prefix: &str,
         opts: ToolVersionOptions,
     ) -> Result<Self> {
-        let matches = tool.list_versions_matching(&config.settings, prefix)?;
+        let matches = tool.list_versions_matching(config, &tool_settings(config, &opts), prefix)?;
+        let matches = sort_by_distribution(prefix, matches);
         let v = match matches.last() {
             Some(v) => v,
             None => prefix,
@@ -180,6 +181,54 @@ impl ToolVersion {
         Ok(Self::new(tool, request, opts, v.to_string()))
     }
 
+    /// resolves orig_version to the newest release, subtracts sub from it and uses the newest
+    /// release with the result as a prefix, e.g.: "sub-0.1:latest" is 20.4.x if latest is 20.5.1
+    /// "latest!-0.1" is the same request
+    fn resolve_sub(
+        config: &Config,
+        tool: &Tool,
+        request: ToolVersionRequest,
+        sub: &str,
+        orig_version: &str,
+        opts: ToolVersionOptions,
+    ) -> Result<Self> {
+        if !tool.is_installed() {
+            let version = request.version();
+            return Ok(Self::new(tool, request, opts, version));
+        }
+        let orig_request = ToolVersionRequest::Version(tool.name.clone(), orig_version.into());
+        let orig = orig_request.resolve(config, tool, opts.clone(), true)?;
+        let prefix = version_sub(&orig.version, sub)?;
+        Self::resolve_prefix(config, tool, request, &prefix, opts)
+    }
+
+    /// the highest installed version that satisfies the range, otherwise the highest remote one
+    fn resolve_range(
+        config: &Config,
+        tool: &Tool,
+        request: ToolVersionRequest,
+        latest_versions: bool,
+        range: &str,
+        opts: ToolVersionOptions,
+    ) -> Result<Self> {
+        let version_range = VersionRange::parse(range)?;
+        if !tool.is_installed() {
+            let version = request.version();
+            return Ok(Self::new(tool, request, opts, version));
+        }
+        if !latest_versions {
+            let installed = tool.list_installed_versions()?;
+            if let Some(v) = version_range.max_satisfying(&installed) {
+                return Ok(Self::new(tool, request, opts, v.clone()));
+            }
+        }
+        let remote = tool.list_remote_versions(config)?;
+        match version_range.max_satisfying(&remote) {
+            Some(v) => Ok(Self::new(tool, request, opts, v.clone())),
+            None => Err(VersionNotFound(tool.name.clone(), range.to_string()))?,
+        }
+    }
+
     fn resolve_ref(tool: &Tool, r: String, opts: ToolVersionOptions) -> Self {
         let request = ToolVersionRequest::Ref(tool.name.clone(), r);
         let version = request.version();
@@ -200,20 +249,47 @@ impl Display for ToolVersion {
     }
 }
 
+/// the `prerelease` tool option overrides the setting, e.g.: `node = {version='21', prerelease='true'}`
+fn tool_settings(config: &Config, opts: &ToolVersionOptions) -> Settings {
+    let mut settings = config.settings.clone();
+    if let Some(prerelease) = opts.get("prerelease") {
+        settings.prerelease = prerelease == "true";
+    }
+    settings
+}
+
+/// when the prefix names a distribution like "temurin-17", sorts the matches by the version
+/// after the distribution so the newest release of that distribution is picked
+fn sort_by_distribution(prefix: &str, matches: Vec<String>) -> Vec<String> {
+    let distribution = match regex!(r"^([a-z][a-z0-9_-]*-)[0-9]").captures(prefix) {
+        Some(c) => c[1].to_string(),
+        None => return matches,
+    };
+    matches
+        .into_iter()
+        .sorted_by_cached_key(|v| Versioning::new(v.strip_prefix(&distribution).unwrap_or(v)))
+        .collect()
+}
+
 /// subtracts sub from orig and removes suffix
 /// e.g. version_sub("18.2.3", "2") -> "16"
 /// e.g. version_sub("18.2.3", "0.1") -> "18.1"
-fn version_sub(orig: &str, sub: &str) -> String {
-    let mut orig = Version::new(orig).unwrap();
-    let sub = Version::new(sub).unwrap();
-    while orig.chunks.0.len() > sub.chunks.0.len() {
-        orig.chunks.0.pop();
+fn version_sub(orig: &str, sub: &str) -> Result<String> {
+    let invalid = || eyre!("cannot subtract {sub} from version {orig}");
+    let mut v = Version::new(orig).ok_or_else(invalid)?;
+    let sub = Version::new(sub).ok_or_else(invalid)?;
+    while v.chunks.0.len() > sub.chunks.0.len() {
+        v.chunks.0.pop();
     }
-    for (i, orig_chunk) in orig.clone().chunks.0.iter().enumerate() {
-        let m = sub.nth(i).unwrap();
-        orig.chunks.0[i] = Chunk::Numeric(orig_chunk.single_digit().unwrap() - m);
+    for (i, chunk) in v.clone().chunks.0.iter().enumerate() {
+        let n = chunk
+            .single_digit()
+            .zip(sub.nth(i))
+            .and_then(|(n, m)| n.checked_sub(m))
+            .ok_or_else(invalid)?;
+        v.chunks.0[i] = Chunk::Numeric(n);
     }
-    orig.to_string()
+    Ok(v.to_string())
 }
 
 #[cfg(test)]
@@ -221,10 +297,63 @@ mod tests {
     use pretty_assertions::assert_str_eq;
 
     use super::*;
+    use crate::plugins::ExternalPlugin;
 
     #[test]
     fn test_version_sub() {
-        assert_str_eq!(version_sub("18.2.3", "2"), "16");
-        assert_str_eq!(version_sub("18.2.3", "0.1"), "18.1");
+        assert_str_eq!(version_sub("18.2.3", "2").unwrap(), "16");
+        assert_str_eq!(version_sub("18.2.3", "0.1").unwrap(), "18.1");
+        assert_str_eq!(
+            version_sub("1.2.3", "2").unwrap_err().to_string(),
+            "cannot subtract 2 from version 1.2.3"
+        );
+    }
+
+    #[test]
+    fn test_tool_settings() {
+        let config = Config::default();
+        let opts = ToolVersionOptions::from([("prerelease".into(), "true".into())]);
+        assert!(tool_settings(&config, &opts).prerelease);
+        assert!(!tool_settings(&config, &Default::default()).prerelease);
+    }
+
+    #[test]
+    fn test_sort_by_distribution() {
+        let matches = vec!["temurin-17.0.10".into(), "temurin-17.0.9".into()];
+        assert_eq!(
+            sort_by_distribution("temurin-17", matches.clone()),
+            vec!["temurin-17.0.9", "temurin-17.0.10"]
+        );
+        assert_eq!(sort_by_distribution("17", matches.clone()), matches);
+    }
+
+    #[test]
+    fn test_resolve_bang() {
+        let config = Config::load().unwrap();
+        let tool = config.tools.get("tiny").unwrap();
+        for (v, expected) in [("3.1!-0.1", "3.0.1"), ("latest!-1", "2.1.0")] {
+            let request = ToolVersionRequest::Version("tiny".into(), v.into());
+            let tv = request.resolve(&config, tool, Default::default(), true);
+            assert_str_eq!(tv.unwrap().version, expected);
+        }
+        let request = ToolVersionRequest::Version("tiny".into(), "1!-2".into());
+        let err = request.resolve(&config, tool, Default::default(), true);
+        assert_str_eq!(
+            err.unwrap_err().to_string(),
+            "cannot subtract 2 from version 1.0.1"
+        );
+    }
+
+    #[test]
+    fn test_resolve_range_plugin_not_installed() {
+        let config = Config::default();
+        let plugin_name = "tinyx".to_string();
+        let tool = Tool::new(
+            plugin_name.clone(),
+            Box::new(ExternalPlugin::new(&plugin_name)),
+        );
+        let request = ToolVersionRequest::Range(plugin_name, "^3".into());
+        let tv = request.resolve(&config, &tool, Default::default(), false);
+        assert_str_eq!(tv.unwrap().version, "^3");
     }
 }
@@ -56,7 +56,7 @@ mod tests {
         config.tools.insert(plugin_name.clone(), Arc::new(tool));
         let mut tvl = ToolVersionList::new(plugin_name.clone(), ToolSource::Argument);
         tvl.requests.push((
-            ToolVersionRequest::new(plugin_name, "latest"),
+            ToolVersionRequest::Version(plugin_name, "latest".into()),
             ToolVersionOptions::default(),
         ));
         tvl.resolve(&config, true);
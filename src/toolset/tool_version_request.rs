@@ -1,47 +1,66 @@
 use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
 
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 
 use crate::config::Config;
 use crate::plugins::PluginName;
 use crate::tool::Tool;
-use crate::toolset::{ToolVersion, ToolVersionOptions};
+use crate::toolset::{ToolVersion, ToolVersionOptions, VersionRange};
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum ToolVersionRequest {
     Version(PluginName, String),
     Prefix(PluginName, String),
+    Range(PluginName, String),
+    /// e.g.: "sub-1:latest" is the newest release of the major before the latest one
+    Sub {
+        plugin_name: PluginName,
+        sub: String,
+        orig_version: String,
+    },
     Ref(PluginName, String),
     Path(PluginName, PathBuf),
     System(PluginName),
 }
 
 impl ToolVersionRequest {
-    pub fn new(plugin_name: PluginName, s: &str) -> Self {
+    pub fn new(plugin_name: PluginName, s: &str) -> Result<Self> {
         let s = match s.split_once('-') {
             Some(("ref", r)) => format!("ref:{}", r),
             _ => s.to_string(),
         };
-        match s.split_once(':') {
+        Ok(match s.split_once(':') {
             Some(("ref", r)) => Self::Ref(plugin_name, r.to_string()),
             Some(("prefix", p)) => Self::Prefix(plugin_name, p.to_string()),
             Some(("path", p)) => Self::Path(plugin_name, PathBuf::from(p)),
-            None => {
-                if s == "system" {
-                    Self::System(plugin_name)
-                } else {
-                    Self::Version(plugin_name, s.to_string())
+            Some((p, v)) if p.starts_with("sub-") => {
+                let sub = &p[4..];
+                if !regex!(r"^\d+(\.\d+)*$").is_match(sub) {
+                    return Err(eyre!("invalid version offset: {sub}"));
+                }
+                Self::Sub {
+                    plugin_name,
+                    sub: sub.to_string(),
+                    orig_version: v.to_string(),
                 }
             }
-            _ => panic!("invalid tool version request: {s}"),
-        }
+            Some(_) => return Err(eyre!("invalid tool version request: {s}")),
+            None if s == "system" => Self::System(plugin_name),
+            None if VersionRange::is_range(&s) => {
+                VersionRange::parse(&s)?;
+                Self::Range(plugin_name, s)
+            }
+            None => Self::Version(plugin_name, s),
+        })
     }
 
     pub fn plugin_name(&self) -> &PluginName {
         match self {
             Self::Version(p, _) => p,
             Self::Prefix(p, _) => p,
+            Self::Range(p, _) => p,
+            Self::Sub { plugin_name, .. } => plugin_name,
             Self::Ref(p, _) => p,
             Self::Path(p, _) => p,
             Self::System(p) => p,
@@ -52,6 +71,10 @@ impl ToolVersionRequest {
         match self {
             Self::Version(_, v) => v.clone(),
             Self::Prefix(_, p) => format!("prefix:{p}"),
+            Self::Range(_, r) => r.clone(),
+            Self::Sub {
+                sub, orig_version, ..
+            } => format!("sub-{sub}:{orig_version}"),
             Self::Ref(_, r) => format!("ref:{r}"),
             Self::Path(_, p) => format!("path:{}", p.display()),
             Self::System(_) => "system".to_string(),
@@ -0,0 +1,124 @@
+use color_eyre::eyre::{eyre, Result};
+use itertools::Itertools;
+use semver::{Version, VersionReq};
+
+/// an npm/cargo-style semver range, e.g.: "^18", "~3.11.2", ">=1.20 <1.22" or "^16 || ^18"
+#[derive(Debug, Clone)]
+pub struct VersionRange(Vec<VersionReq>);
+
+impl VersionRange {
+    /// ranges start with an operator, other versions are exact or fuzzy matches
+    pub fn is_range(s: &str) -> bool {
+        s.starts_with(['^', '~', '<', '>', '='])
+    }
+
+    pub fn parse(s: &str) -> Result<Self> {
+        let reqs = s
+            .split("||")
+            .map(|set| {
+                let comparators = normalize_comparators(set);
+                VersionReq::parse(&comparators)
+                    .map_err(|err| eyre!("invalid version range {}: {}", s.trim(), err))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self(reqs))
+    }
+
+    pub fn matches(&self, version: &str) -> bool {
+        match parse_version(version) {
+            Some(version) => self.0.iter().any(|req| req.matches(&version)),
+            None => false,
+        }
+    }
+
+    /// the highest of the versions that satisfies the range
+    pub fn max_satisfying<'a>(&self, versions: &'a [String]) -> Option<&'a String> {
+        versions
+            .iter()
+            .filter(|v| self.matches(v))
+            .max_by_key(|v| parse_version(v))
+    }
+}
+
+/// npm separates comparators with spaces and allows a space after the operator, semver expects
+/// commas, e.g.: ">= 1.20 <1.22" -> ">=1.20, <1.22"
+fn normalize_comparators(set: &str) -> String {
+    let mut comparators: Vec<String> = vec![];
+    let mut op = String::new();
+    for token in set.split(|c: char| c == ',' || c.is_whitespace()) {
+        if token.is_empty() {
+            continue;
+        }
+        if token.chars().all(|c| "^~<>=".contains(c)) {
+            op.push_str(token);
+        } else {
+            comparators.push(format!("{op}{token}"));
+            op.clear();
+        }
+    }
+    if !op.is_empty() {
+        comparators.push(op);
+    }
+    comparators.into_iter().join(", ")
+}
+
+/// versions of tools are often missing the minor or patch part, e.g.: "1.20" -> "1.20.0"
+/// versions that are not semver like "3.12.0rc1" never match a range
+fn parse_version(v: &str) -> Option<Version> {
+    let v = v.trim_start_matches('v');
+    let (core, rest) = v.split_at(v.find(['-', '+']).unwrap_or(v.len()));
+    let padding = match core.split('.').count() {
+        1 => ".0.0",
+        2 => ".0",
+        _ => "",
+    };
+    Version::parse(&format!("{core}{padding}{rest}")).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_range() {
+        assert!(VersionRange::is_range("^18"));
+        assert!(VersionRange::is_range(">=1.20 <1.22"));
+        assert!(!VersionRange::is_range("18"));
+        assert!(!VersionRange::is_range("latest"));
+    }
+
+    #[test]
+    fn test_max_satisfying() {
+        let versions = [
+            "1.19.5",
+            "1.20",
+            "1.20.3",
+            "1.21.0",
+            "1.22.0",
+            "2.0.0-rc.1",
+            "3.12.0rc1",
+        ]
+        .map(String::from);
+        let max = |range: &str| {
+            let range = VersionRange::parse(range).unwrap();
+            range.max_satisfying(&versions).cloned()
+        };
+        assert_eq!(max("^1").as_deref(), Some("1.22.0"));
+        assert_eq!(max("~1.20").as_deref(), Some("1.20.3"));
+        assert_eq!(max(">=1.20 <1.22").as_deref(), Some("1.21.0"));
+        assert_eq!(max(">= 1.20, < 1.21").as_deref(), Some("1.20.3"));
+        assert_eq!(max("=1.20").as_deref(), Some("1.20.3"));
+        assert_eq!(max("^1.19 <1.20 || ^2").as_deref(), Some("1.19.5"));
+        assert_eq!(max("^4"), None);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = VersionRange::parse("^x").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid version range ^x: unexpected character 'x' while parsing major version number"
+        );
+        assert!(VersionRange::parse(">=").is_err());
+    }
+}